@@ -0,0 +1,41 @@
+use super::component_prelude::*;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Switches the animation of an entity with a `Checkpoint`
+/// and an `AnimationsContainer`, when the checkpoint is activated
+/// or deactivated in the `Checkpoints` resource.
+/// Plays the `active` animation while the checkpoint is active,
+/// and the optional `inactive` animation while it isn't.
+/// Handled by the `SwitchCheckpointAnimationsSystem`.
+#[derive(Component, Clone, Deserialize)]
+#[storage(HashMapStorage)]
+#[serde(deny_unknown_fields)]
+pub struct CheckpointAnimations<K>
+where
+    K: 'static + Hash + Eq + Send + Sync + Clone + Debug,
+{
+    pub(crate) active:    K,
+    #[serde(default)]
+    pub(crate) inactive:  Option<K>,
+    #[serde(skip)]
+    pub(crate) is_active: Option<bool>,
+}
+
+impl<K> CheckpointAnimations<K>
+where
+    K: 'static + Hash + Eq + Send + Sync + Clone + Debug,
+{
+    pub fn new(active: K) -> Self {
+        Self {
+            active,
+            inactive: None,
+            is_active: None,
+        }
+    }
+
+    pub fn with_inactive(mut self, inactive: K) -> Self {
+        self.inactive = Some(inactive);
+        self
+    }
+}
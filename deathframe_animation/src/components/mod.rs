@@ -1,6 +1,7 @@
 pub mod prelude {
     pub use super::animation::Animation;
     pub use super::animations_container::AnimationsContainer;
+    pub use super::checkpoint_animations::CheckpointAnimations;
 }
 
 mod component_prelude {
@@ -11,3 +12,4 @@ mod component_prelude {
 
 mod animation;
 mod animations_container;
+mod checkpoint_animations;
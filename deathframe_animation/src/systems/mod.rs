@@ -1,6 +1,7 @@
 pub mod prelude {
     pub use super::play_animations::PlayAnimationsSystem;
     pub use super::switch_animations::SwitchAnimationsSystem;
+    pub use super::switch_checkpoint_animations::SwitchCheckpointAnimationsSystem;
 }
 
 mod system_prelude {
@@ -10,3 +11,4 @@ mod system_prelude {
 
 mod play_animations;
 mod switch_animations;
+mod switch_checkpoint_animations;
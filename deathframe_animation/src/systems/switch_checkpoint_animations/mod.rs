@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Plays `CheckpointAnimations`' animations, when the entity's
+/// `Checkpoint` is activated or deactivated in the `Checkpoints` resource.
+pub struct SwitchCheckpointAnimationsSystem<K>
+where
+    K: Hash + Eq + Send + Sync + Debug + Clone,
+{
+    _k: PhantomData<K>,
+}
+
+impl<'a, K> System<'a> for SwitchCheckpointAnimationsSystem<K>
+where
+    K: 'static + Hash + Eq + Send + Sync + Debug + Clone,
+{
+    type SystemData = (
+        Read<'a, Checkpoints>,
+        ReadStorage<'a, Checkpoint>,
+        WriteStorage<'a, CheckpointAnimations<K>>,
+        WriteStorage<'a, AnimationsContainer<K>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            checkpoints,
+            checkpoint_store,
+            mut checkpoint_animations_store,
+            mut animations_container_store,
+        ): Self::SystemData,
+    ) {
+        for (checkpoint, checkpoint_animations, animations_container) in (
+            &checkpoint_store,
+            &mut checkpoint_animations_store,
+            &mut animations_container_store,
+        )
+            .join()
+        {
+            let is_active = checkpoints.is_active(checkpoint.id());
+            if checkpoint_animations.is_active == Some(is_active) {
+                continue;
+            }
+            checkpoint_animations.is_active = Some(is_active);
+
+            let key_opt = if is_active {
+                Some(checkpoint_animations.active.clone())
+            } else {
+                checkpoint_animations.inactive.clone()
+            };
+            if let Some(key) = key_opt {
+                if let Err(e) = animations_container.play(key) {
+                    eprintln!(
+                        "[WARNING]\n    Couldn't play checkpoint \
+                         animation\n    {}",
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<K> Default for SwitchCheckpointAnimationsSystem<K>
+where
+    K: Hash + Eq + Send + Sync + Debug + Clone,
+{
+    fn default() -> Self {
+        Self {
+            _k: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Test switching of checkpoint animations
+//! by the `SwitchCheckpointAnimationsSystem`
+
+use super::*;
+use core::amethyst::ecs::{Builder, RunNow, WorldExt};
+use std::collections::HashMap;
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum AnimKey {
+    Active,
+    Inactive,
+}
+
+fn setup() -> (World, SwitchCheckpointAnimationsSystem<AnimKey>) {
+    let mut world = World::new();
+    let mut system = SwitchCheckpointAnimationsSystem::<AnimKey>::default();
+    System::setup(&mut system, &mut world);
+    (world, system)
+}
+
+fn create_checkpoint(
+    world: &mut World,
+    checkpoint_animations: CheckpointAnimations<AnimKey>,
+) -> Entity {
+    let mut animations = HashMap::new();
+    animations.insert(AnimKey::Active, vec![(0_usize, 100_u64)]);
+    animations.insert(AnimKey::Inactive, vec![(1_usize, 100_u64)]);
+    world
+        .create_entity()
+        .with(Checkpoint::new("first"))
+        .with(checkpoint_animations)
+        .with(AnimationsContainer::from(animations))
+        .build()
+}
+
+fn activate_checkpoint(world: &World) {
+    world.write_resource::<Checkpoints>().activate(CheckpointData {
+        id:          String::from("first"),
+        spawn_point: (0.0, 0.0),
+    });
+}
+
+fn current_animation(world: &World, entity: Entity) -> Option<AnimKey> {
+    world
+        .read_storage::<AnimationsContainer<AnimKey>>()
+        .get(entity)
+        .unwrap()
+        .current()
+        .cloned()
+}
+
+#[test]
+fn switches_between_active_and_inactive_animations() {
+    let (mut world, mut system) = setup();
+    let entity = create_checkpoint(
+        &mut world,
+        CheckpointAnimations::new(AnimKey::Active)
+            .with_inactive(AnimKey::Inactive),
+    );
+
+    system.run_now(&world);
+    assert_eq!(current_animation(&world, entity), Some(AnimKey::Inactive));
+
+    activate_checkpoint(&world);
+    system.run_now(&world);
+    assert_eq!(current_animation(&world, entity), Some(AnimKey::Active));
+
+    world.write_resource::<Checkpoints>().clear();
+    system.run_now(&world);
+    assert_eq!(current_animation(&world, entity), Some(AnimKey::Inactive));
+}
+
+#[test]
+fn keeps_animation_without_inactive_animation() {
+    let (mut world, mut system) = setup();
+    let checkpoint_animations = CheckpointAnimations::new(AnimKey::Active);
+    let entity = create_checkpoint(&mut world, checkpoint_animations);
+
+    system.run_now(&world);
+    assert_eq!(current_animation(&world, entity), None);
+
+    activate_checkpoint(&world);
+    system.run_now(&world);
+    assert_eq!(current_animation(&world, entity), Some(AnimKey::Active));
+
+    world.write_resource::<Checkpoints>().clear();
+    system.run_now(&world);
+    assert_eq!(current_animation(&world, entity), Some(AnimKey::Active));
+}
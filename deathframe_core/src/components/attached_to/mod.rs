@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+use amethyst::core::math::Vector3;
+use amethyst::core::transform::Transform;
+
+/// Attaches this entity to a `parent` entity, like a weapon,
+/// a shield, or a name label attached to a character.
+/// With the `UpdateAttachmentsSystem`, the child's `Transform`
+/// is positioned at the parent's position, plus the `offset`.
+/// Optionally, the child also inherits the parent's _flip_
+/// (its `Facing`, or the sign of its `Transform` scale)
+/// and the parent's `Transform` scale. Inherited flip and scale
+/// also apply to the offset.
+///
+/// The child's `Unloaded` and `Hidden` state always match its parent's,
+/// so attached children shouldn't be `Loadable` themselves.
+/// When the parent is deleted or switches to `LifecycleState::Despawn`,
+/// the child switches to `LifecycleState::Death`,
+/// or is deleted, if it has no `Lifecycle`.
+#[derive(Clone)]
+pub struct AttachedTo {
+    pub(crate) parent:        Entity,
+    pub(crate) offset:        (f32, f32),
+    pub(crate) inherit_flip:  bool,
+    pub(crate) inherit_scale: bool,
+    pub(crate) base_scale:    Option<Vector3<f32>>,
+}
+
+impl AttachedTo {
+    /// Attach this entity to the given parent entity.
+    pub fn new(parent: Entity) -> Self {
+        Self {
+            parent,
+            offset: (0.0, 0.0),
+            inherit_flip: false,
+            inherit_scale: false,
+            base_scale: None,
+        }
+    }
+
+    /// Position this entity with an offset from its parent.
+    pub fn with_offset(mut self, offset: (f32, f32)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Flip this entity (and its offset) with its parent.
+    pub fn with_inherit_flip(mut self, inherit_flip: bool) -> Self {
+        self.inherit_flip = inherit_flip;
+        self
+    }
+
+    /// Scale this entity (and its offset) with its parent's scale.
+    pub fn with_inherit_scale(mut self, inherit_scale: bool) -> Self {
+        self.inherit_scale = inherit_scale;
+        self
+    }
+
+    /// Returns the parent entity.
+    pub fn parent(&self) -> Entity {
+        self.parent
+    }
+
+    /// Positions (and flips and scales) the child's `Transform`,
+    /// from the parent's `Transform` and flip per axis.
+    /// The child's own scale is remembered the first time,
+    /// and is used as the base for the inherited flip and scale.
+    pub(crate) fn update(
+        &mut self,
+        parent: &Transform,
+        parent_flip: (bool, bool),
+        child: &mut Transform,
+    ) {
+        let parent_scale = parent.scale();
+        let multiplier = if self.inherit_scale {
+            (parent_scale.x.abs(), parent_scale.y.abs())
+        } else {
+            (1.0, 1.0)
+        };
+        let sign = if self.inherit_flip {
+            (
+                if parent_flip.0 { -1.0 } else { 1.0 },
+                if parent_flip.1 { -1.0 } else { 1.0 },
+            )
+        } else {
+            (1.0, 1.0)
+        };
+
+        let parent_pos = parent.translation();
+        child.set_translation_x(
+            parent_pos.x + self.offset.0 * multiplier.0 * sign.0,
+        );
+        child.set_translation_y(
+            parent_pos.y + self.offset.1 * multiplier.1 * sign.1,
+        );
+
+        if self.inherit_flip || self.inherit_scale {
+            let base_scale =
+                *self.base_scale.get_or_insert_with(|| *child.scale());
+            child.set_scale(Vector3::new(
+                base_scale.x * multiplier.0 * sign.0,
+                base_scale.y * multiplier.1 * sign.1,
+                base_scale.z,
+            ));
+        }
+    }
+}
+
+impl Component for AttachedTo {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -0,0 +1,54 @@
+//! Test the attached_to component
+
+use super::AttachedTo;
+use amethyst::core::math::Vector3;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{World, WorldExt};
+
+fn get_attached_to() -> AttachedTo {
+    let world = World::new();
+    let parent = world.entities().create();
+    AttachedTo::new(parent).with_offset((4.0, 2.0))
+}
+
+fn get_parent() -> Transform {
+    let mut parent = Transform::default();
+    parent.set_translation_xyz(10.0, 20.0, 0.0);
+    parent.set_scale(Vector3::new(-2.0, 2.0, 1.0));
+    parent
+}
+
+#[test]
+fn inherits_position_with_offset() {
+    let mut attached_to = get_attached_to();
+    let mut child = Transform::default();
+    attached_to.update(&get_parent(), (true, false), &mut child);
+    assert_eq!(child.translation().x, 14.0);
+    assert_eq!(child.translation().y, 22.0);
+    assert_eq!(child.scale(), &Vector3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn inherits_flip() {
+    let mut attached_to = get_attached_to().with_inherit_flip(true);
+    let mut child = Transform::default();
+    attached_to.update(&get_parent(), (true, false), &mut child);
+    assert_eq!(child.translation().x, 6.0);
+    assert_eq!(child.scale(), &Vector3::new(-1.0, 1.0, 1.0));
+    attached_to.update(&get_parent(), (false, false), &mut child);
+    assert_eq!(child.translation().x, 14.0);
+    assert_eq!(child.scale(), &Vector3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn inherits_scale() {
+    let mut attached_to = get_attached_to()
+        .with_inherit_flip(true)
+        .with_inherit_scale(true);
+    let mut child = Transform::default();
+    child.set_scale(Vector3::new(0.5, 0.5, 1.0));
+    attached_to.update(&get_parent(), (true, false), &mut child);
+    assert_eq!(child.translation().x, 2.0);
+    assert_eq!(child.translation().y, 24.0);
+    assert_eq!(child.scale(), &Vector3::new(-1.0, 1.0, 1.0));
+}
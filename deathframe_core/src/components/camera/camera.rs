@@ -0,0 +1,268 @@
+use super::camera_smoothing::CameraSmoothing;
+use super::component_prelude::*;
+use crate::components::prelude::Size;
+
+/// The target has to move faster than this (units per second)
+/// on an axis, to change the look-ahead direction on that axis.
+const LOOK_AHEAD_THRESHOLD: f32 = 1.0;
+
+/// Moves the entity's `Transform` (usually the entity with the
+/// amethyst `Camera`) towards its `target` entity,
+/// with the `UpdateCameraSystem`.
+/// Unlike `Follow` and `Confined`, the camera moves smoothly
+/// (see `CameraSmoothing`), can ignore movement inside a dead zone,
+/// can look ahead in the direction the target is moving,
+/// and confines its _visible area_ to the level's `bounds`,
+/// instead of only its center.
+///
+/// The visible area is taken from the entity's amethyst `Camera`
+/// orthographic projection (scaled by its `Transform`),
+/// or from its `Size`, if it has no orthographic projection.
+#[derive(Component, Clone, Default, Deserialize)]
+#[storage(HashMapStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Camera {
+    #[serde(skip)]
+    pub(crate) target:          Option<Entity>,
+    #[serde(default)]
+    pub(crate) offset:          (f32, f32),
+    #[serde(default)]
+    pub(crate) smoothing:       CameraSmoothing,
+    pub(crate) dead_zone:       Option<Size>,
+    #[serde(default)]
+    pub(crate) look_ahead:      (f32, f32),
+    pub(crate) bounds:          Option<Rect>,
+    #[serde(skip)]
+    pub(crate) position:        Option<(f32, f32)>,
+    #[serde(skip)]
+    pub(crate) velocity:        (f32, f32),
+    #[serde(skip)]
+    pub(crate) last_target_pos: Option<(f32, f32)>,
+    #[serde(skip)]
+    pub(crate) look_direction:  (f32, f32),
+    #[serde(skip)]
+    pub(crate) viewport:        Rect,
+}
+
+impl Camera {
+    /// Create a new `Camera` without a target,
+    /// which snaps directly to its target (no smoothing).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Follow the given target entity.
+    pub fn with_target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Offset the camera's position from its target's position.
+    pub fn with_offset(mut self, offset: (f32, f32)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set how the camera moves towards its target.
+    pub fn with_smoothing(mut self, smoothing: CameraSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// The target can move freely inside the dead zone
+    /// (centered on the camera), without moving the camera.
+    pub fn with_dead_zone(mut self, dead_zone: Size) -> Self {
+        self.dead_zone = Some(dead_zone);
+        self
+    }
+
+    /// Look ahead by the given distance per axis,
+    /// in the direction the target is moving.
+    pub fn with_look_ahead(mut self, look_ahead: (f32, f32)) -> Self {
+        self.look_ahead = look_ahead;
+        self
+    }
+
+    /// Confine the camera's visible area to the given level `Rect`.
+    pub fn with_bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Returns the camera's target entity, if it has one.
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    /// Set or remove the camera's target entity.
+    pub fn set_target(&mut self, target: Option<Entity>) {
+        self.target = target;
+        self.last_target_pos = None;
+    }
+
+    /// Set or remove the level `Rect` to confine the visible area to.
+    pub fn set_bounds(&mut self, bounds: Option<Rect>) {
+        self.bounds = bounds;
+    }
+
+    /// Returns the camera's current position, as calculated
+    /// by the `UpdateCameraSystem`. `None` before its first update.
+    pub fn position(&self) -> Option<(f32, f32)> {
+        self.position
+    }
+
+    /// Jump directly to the target on the next update, without smoothing.
+    /// Useful after teleporting the target, for example when respawning.
+    pub fn snap(&mut self) {
+        self.position = None;
+        self.velocity = (0.0, 0.0);
+        self.last_target_pos = None;
+        self.look_direction = (0.0, 0.0);
+    }
+
+    /// Returns the new camera position after `dt` seconds.
+    /// `current_pos` is only used before the first update,
+    /// if there is no target to snap to.
+    /// `viewport` is the visible area, relative to the camera's position.
+    pub(crate) fn update(
+        &mut self,
+        dt: f32,
+        current_pos: (f32, f32),
+        target_pos_opt: Option<(f32, f32)>,
+        viewport: &Rect,
+    ) -> (f32, f32) {
+        if let Some(target_pos) = target_pos_opt {
+            self.update_look_direction(dt, target_pos);
+        }
+        self.last_target_pos = target_pos_opt;
+
+        let desired_opt = target_pos_opt.map(|target_pos| {
+            (
+                target_pos.0
+                    + self.offset.0
+                    + self.look_direction.0 * self.look_ahead.0,
+                target_pos.1
+                    + self.offset.1
+                    + self.look_direction.1 * self.look_ahead.1,
+            )
+        });
+
+        let mut position = match self.position {
+            Some(position) => {
+                let goal = desired_opt
+                    .map(|desired| self.dead_zone_goal(position, desired))
+                    .unwrap_or(position);
+                let (x, vel_x) = self.smoothing.step(
+                    dt,
+                    position.0,
+                    self.velocity.0,
+                    goal.0,
+                );
+                let (y, vel_y) = self.smoothing.step(
+                    dt,
+                    position.1,
+                    self.velocity.1,
+                    goal.1,
+                );
+                self.velocity = (vel_x, vel_y);
+                (x, y)
+            }
+            None => {
+                self.velocity = (0.0, 0.0);
+                desired_opt.unwrap_or(current_pos)
+            }
+        };
+
+        self.viewport = viewport.clone();
+        let confined = self.confine(position);
+        if confined.0 != position.0 {
+            self.velocity.0 = 0.0;
+        }
+        if confined.1 != position.1 {
+            self.velocity.1 = 0.0;
+        }
+        position = confined;
+
+        self.position = Some(position);
+        position
+    }
+
+    /// Returns the given position, confined so the visible area
+    /// (from the last update) stays inside the camera's bounds.
+    pub(crate) fn confine(&self, position: (f32, f32)) -> (f32, f32) {
+        if let Some(bounds) = self.bounds.as_ref() {
+            (
+                confine_axis(
+                    position.0,
+                    (self.viewport.left, self.viewport.right),
+                    (bounds.left, bounds.right),
+                ),
+                confine_axis(
+                    position.1,
+                    (self.viewport.bottom, self.viewport.top),
+                    (bounds.bottom, bounds.top),
+                ),
+            )
+        } else {
+            position
+        }
+    }
+
+    fn update_look_direction(&mut self, dt: f32, target_pos: (f32, f32)) {
+        if let Some(last_pos) = self.last_target_pos.filter(|_| dt > 0.0) {
+            let velocity = (
+                (target_pos.0 - last_pos.0) / dt,
+                (target_pos.1 - last_pos.1) / dt,
+            );
+            if velocity.0.abs() > LOOK_AHEAD_THRESHOLD {
+                self.look_direction.0 = velocity.0.signum();
+            }
+            if velocity.1.abs() > LOOK_AHEAD_THRESHOLD {
+                self.look_direction.1 = velocity.1.signum();
+            }
+        }
+    }
+
+    /// Returns the position to move towards, so the `desired` position
+    /// is just inside the dead zone around the given `position`.
+    fn dead_zone_goal(
+        &self,
+        position: (f32, f32),
+        desired: (f32, f32),
+    ) -> (f32, f32) {
+        if let Some(dead_zone) = self.dead_zone.as_ref() {
+            let half = dead_zone.half();
+            (
+                dead_zone_axis(position.0, desired.0, half.w),
+                dead_zone_axis(position.1, desired.1, half.h),
+            )
+        } else {
+            desired
+        }
+    }
+}
+
+fn dead_zone_axis(position: f32, desired: f32, half_size: f32) -> f32 {
+    let distance = desired - position;
+    if distance.abs() <= half_size {
+        position
+    } else {
+        desired - half_size * distance.signum()
+    }
+}
+
+/// Confines the position on a single axis, so the viewport
+/// (relative to the position) stays inside the bounds.
+/// Centers the viewport on the bounds, if it is larger than the bounds.
+fn confine_axis(
+    position: f32,
+    viewport: (f32, f32),
+    bounds: (f32, f32),
+) -> f32 {
+    let (min, max) = (bounds.0 - viewport.0, bounds.1 - viewport.1);
+    if min > max {
+        (bounds.0 + bounds.1) * 0.5 - (viewport.0 + viewport.1) * 0.5
+    } else {
+        position.max(min).min(max)
+    }
+}
@@ -0,0 +1,235 @@
+use super::camera::Camera;
+use super::camera_zoom::CameraZoom;
+use super::component_prelude::*;
+use amethyst::core::math::UnitQuaternion;
+use amethyst::core::transform::Transform;
+
+/// Shakes the entity's `Transform` (usually the camera),
+/// with the `UpdateCameraShakeSystem`.
+/// The shake's strength is its _trauma_ (`0.0` to `1.0`),
+/// which decays over time. Gameplay code adds trauma through
+/// the `CameraShakeAction`s of this action queue,
+/// for example with `camera_shake.add_trauma(0.5)`.
+///
+/// The offsets are generated from smooth noise and scale with
+/// the square of the trauma, up to `max_offset` and `max_rotation`
+/// (in radians). They are added on top of the entity's position
+/// at the end of the frame, and removed again at the start
+/// of the next frame by the `RestoreCameraShakeSystem`, so other
+/// systems never see the shaken position.
+#[derive(Component, Clone, Deserialize)]
+#[storage(HashMapStorage)]
+#[serde(deny_unknown_fields)]
+pub struct CameraShake {
+    pub(crate) max_offset:   (f32, f32),
+    #[serde(default)]
+    pub(crate) max_rotation: f32,
+    #[serde(default = "default_frequency")]
+    pub(crate) frequency:    f32,
+    #[serde(default = "default_decay")]
+    pub(crate) decay:        f32,
+    #[serde(skip)]
+    pub(crate) trauma:       f32,
+    #[serde(skip)]
+    pub(crate) time:         f32,
+    #[serde(skip)]
+    applied:                 Option<AppliedShake>,
+    #[serde(skip)]
+    actions:                 Vec<CameraShakeAction>,
+}
+
+impl CameraShake {
+    /// Create a new `CameraShake`, which offsets the position
+    /// by at most the given `max_offset` per axis, at full trauma.
+    pub fn new(max_offset: (f32, f32)) -> Self {
+        Self {
+            max_offset,
+            max_rotation: 0.0,
+            frequency: default_frequency(),
+            decay: default_decay(),
+            trauma: 0.0,
+            time: 0.0,
+            applied: None,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Rotate by at most the given radians, at full trauma.
+    /// Default is `0.0`, no rotation.
+    pub fn with_max_rotation(mut self, max_rotation: f32) -> Self {
+        self.max_rotation = max_rotation;
+        self
+    }
+
+    /// Set how fast the shake changes direction.
+    /// Default is `15.0`.
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Set how much trauma is lost per second.
+    /// Default is `1.0`.
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Add trauma, up to a total trauma of `1.0`.
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.add_action(CameraShakeAction::AddTrauma(trauma))
+    }
+
+    /// Set the trauma, clamped between `0.0` and `1.0`.
+    pub fn set_trauma(&mut self, trauma: f32) {
+        self.add_action(CameraShakeAction::SetTrauma(trauma))
+    }
+
+    /// Stop shaking immediately.
+    pub fn stop(&mut self) {
+        self.add_action(CameraShakeAction::Stop)
+    }
+
+    /// Returns the current trauma.
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// Returns `true` if there is any trauma left.
+    pub fn is_shaking(&self) -> bool {
+        self.trauma > 0.0
+    }
+
+    /// Handles queued actions and decays the trauma over `dt` seconds.
+    /// Returns the positional offsets and rotation to apply this frame,
+    /// or `None`, if there is no trauma left.
+    pub(crate) fn update(&mut self, dt: f32) -> Option<(f32, f32, f32)> {
+        let mut trauma = self.trauma;
+        for action in self.drain_actions() {
+            trauma = match action {
+                CameraShakeAction::AddTrauma(add) => trauma + add,
+                CameraShakeAction::SetTrauma(set) => set,
+                CameraShakeAction::Stop => 0.0,
+            }
+            .max(0.0)
+            .min(1.0);
+        }
+
+        if trauma <= 0.0 {
+            self.trauma = 0.0;
+            self.time = 0.0;
+            return None;
+        }
+
+        let shake = trauma * trauma;
+        let t = self.time * self.frequency;
+        let offsets = (
+            self.max_offset.0 * shake * noise(0, t),
+            self.max_offset.1 * shake * noise(1, t),
+            self.max_rotation * shake * noise(2, t),
+        );
+
+        self.time += dt;
+        self.trauma = (trauma - self.decay.max(0.0) * dt).max(0.0);
+        Some(offsets)
+    }
+
+    /// Removes the offsets, which were applied last frame,
+    /// restoring the exact unshaken position and rotation.
+    pub(crate) fn restore(&mut self, transform: &mut Transform) {
+        if let Some(applied) = self.applied.take() {
+            transform.set_translation_x(applied.base.0);
+            transform.set_translation_y(applied.base.1);
+            transform.set_rotation(applied.base_rotation);
+        }
+    }
+
+    /// Applies the given offsets (see `update`) on top of
+    /// the transform's current position and rotation.
+    /// The shaken position is confined to the `Camera`'s bounds,
+    /// and snapped to pixels by the `CameraZoom`, if given.
+    pub(crate) fn apply(
+        &mut self,
+        transform: &mut Transform,
+        offsets: (f32, f32, f32),
+        camera_opt: Option<&Camera>,
+        camera_zoom_opt: Option<&CameraZoom>,
+    ) {
+        let base = {
+            let translation = transform.translation();
+            (translation.x, translation.y)
+        };
+        let base_rotation = *transform.rotation();
+        let shaken = (base.0 + offsets.0, base.1 + offsets.1);
+        let shaken = camera_opt
+            .map(|camera| camera.confine(shaken))
+            .unwrap_or(shaken);
+        let shaken = camera_zoom_opt
+            .map(|camera_zoom| camera_zoom.snap(shaken))
+            .unwrap_or(shaken);
+
+        transform.set_translation_x(shaken.0);
+        transform.set_translation_y(shaken.1);
+        transform.set_rotation(
+            base_rotation
+                * UnitQuaternion::from_euler_angles(0.0, 0.0, offsets.2),
+        );
+
+        self.applied = Some(AppliedShake {
+            base,
+            base_rotation,
+        });
+    }
+}
+
+impl ActionQueue for CameraShake {
+    type Action = CameraShakeAction;
+    fn mut_actions(&mut self) -> &mut Vec<Self::Action> {
+        &mut self.actions
+    }
+}
+
+/// Actions to control a `CameraShake` with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CameraShakeAction {
+    AddTrauma(f32),
+    SetTrauma(f32),
+    Stop,
+}
+
+/// The unshaken transform values, from when the shake was last applied.
+#[derive(Clone)]
+struct AppliedShake {
+    base:          (f32, f32),
+    base_rotation: UnitQuaternion<f32>,
+}
+
+fn default_frequency() -> f32 {
+    15.0
+}
+
+fn default_decay() -> f32 {
+    1.0
+}
+
+/// Smooth value noise between `-1.0` and `1.0`,
+/// with a different curve for each `seed`.
+fn noise(seed: u32, t: f32) -> f32 {
+    let floor = t.floor();
+    let fract = t - floor;
+    let smooth = fract * fract * (3.0 - 2.0 * fract);
+    let (a, b) = (hash(seed, floor as i32), hash(seed, floor as i32 + 1));
+    a + (b - a) * smooth
+}
+
+/// Returns a pseudo-random value between `-1.0` and `1.0`,
+/// for the given seed and integer position.
+fn hash(seed: u32, n: i32) -> f32 {
+    let mut x = (n as u32)
+        .wrapping_mul(0x27d4_eb2d)
+        .wrapping_add(seed.wrapping_mul(0x1656_67b1));
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x2c1b_3c6d);
+    x ^= x >> 12;
+    x as f32 / std::u32::MAX as f32 * 2.0 - 1.0
+}
@@ -0,0 +1,47 @@
+/// How the `Camera` moves towards its goal position.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum CameraSmoothing {
+    /// Snap directly to the goal position.
+    None,
+    /// Exponentially interpolate towards the goal position.
+    /// A higher `speed` reaches the goal faster.
+    /// Frame-rate independent, the distance to the goal
+    /// shrinks by the factor `e^(-speed * dt)` every frame.
+    Lerp { speed: f32 },
+    /// Accelerate towards the goal position like a damped spring.
+    /// A `damping` of `2 * sqrt(stiffness)` is critically damped,
+    /// less than that overshoots the goal.
+    Spring { stiffness: f32, damping: f32 },
+}
+
+impl CameraSmoothing {
+    /// Returns the new position and velocity for a single axis,
+    /// moving from `pos` towards `goal` over `dt` seconds.
+    pub(crate) fn step(
+        &self,
+        dt: f32,
+        pos: f32,
+        vel: f32,
+        goal: f32,
+    ) -> (f32, f32) {
+        match self {
+            CameraSmoothing::None => (goal, 0.0),
+            CameraSmoothing::Lerp { speed } => {
+                let t = 1.0 - (-speed.max(0.0) * dt).exp();
+                (pos + (goal - pos) * t, 0.0)
+            }
+            CameraSmoothing::Spring { stiffness, damping } => {
+                let accel = stiffness * (goal - pos) - damping * vel;
+                let vel = vel + accel * dt;
+                (pos + vel * dt, vel)
+            }
+        }
+    }
+}
+
+impl Default for CameraSmoothing {
+    fn default() -> Self {
+        CameraSmoothing::None
+    }
+}
@@ -0,0 +1,175 @@
+use super::component_prelude::*;
+use crate::components::prelude::Size;
+
+/// Zoom transitions end, when they are closer than this to the new zoom.
+const ZOOM_EPSILON: f32 = 0.001;
+
+/// Scales the entity's amethyst `Camera` projection to the window size,
+/// with the `UpdateCameraZoomSystem`.
+/// The `resolution` is the area (in world units) to fit into the window,
+/// at a zoom of `1.0`. A higher zoom shows less of the world.
+/// Zoom changes are smoothly transitioned at the given `speed`,
+/// or applied instantly, if there is no speed.
+///
+/// In _pixel-perfect_ mode, one world unit is always an integer amount
+/// of window pixels. The resolution is fit into the window with the
+/// largest integer scale, and zoom is rounded to integer scale steps.
+/// The camera's position is snapped to whole screen pixels.
+/// The remaining window area around the fitted resolution is letterboxed;
+/// it is visible, but isn't part of the camera's confined area
+/// (see `CameraView::letterbox`).
+#[derive(Component, Clone, Deserialize)]
+#[storage(HashMapStorage)]
+#[serde(deny_unknown_fields)]
+pub struct CameraZoom {
+    pub(crate) resolution:    Size,
+    #[serde(default = "default_zoom")]
+    pub(crate) zoom:          f32,
+    pub(crate) speed:         Option<f32>,
+    #[serde(default)]
+    pub(crate) pixel_perfect: bool,
+    #[serde(skip)]
+    pub(crate) current_zoom:  Option<f32>,
+    #[serde(skip)]
+    pub(crate) view:          Option<CameraView>,
+}
+
+impl CameraZoom {
+    /// Create a new `CameraZoom`, fitting the given resolution
+    /// (in world units) into the window.
+    pub fn new(resolution: Size) -> Self {
+        Self {
+            resolution,
+            zoom: default_zoom(),
+            speed: None,
+            pixel_perfect: false,
+            current_zoom: None,
+            view: None,
+        }
+    }
+
+    /// Start with the given zoom. Default is `1.0`.
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Transition zoom changes smoothly. A higher `speed`
+    /// reaches the new zoom faster (see `CameraSmoothing::Lerp`).
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Enable or disable pixel-perfect mode.
+    pub fn with_pixel_perfect(mut self, pixel_perfect: bool) -> Self {
+        self.pixel_perfect = pixel_perfect;
+        self
+    }
+
+    /// Zoom to the given zoom, transitioning smoothly if there is a speed.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+    }
+
+    /// Returns the zoom to transition to.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Returns the current, possibly transitioning, zoom.
+    pub fn current_zoom(&self) -> f32 {
+        self.current_zoom.unwrap_or(self.zoom)
+    }
+
+    /// Returns the `CameraView` from the last update,
+    /// `None` before the first update.
+    pub fn view(&self) -> Option<&CameraView> {
+        self.view.as_ref()
+    }
+
+    /// Transitions the current zoom over `dt` seconds,
+    /// and returns the new `CameraView` for the given window size (pixels).
+    pub(crate) fn update(&mut self, dt: f32, window: (f32, f32)) -> CameraView {
+        let zoom = match (self.current_zoom, self.speed) {
+            (Some(current), Some(speed)) => {
+                let t = 1.0 - (-speed.max(0.0) * dt).exp();
+                let zoom = current + (self.zoom - current) * t;
+                if (self.zoom - zoom).abs() < ZOOM_EPSILON {
+                    self.zoom
+                } else {
+                    zoom
+                }
+            }
+            _ => self.zoom,
+        };
+        self.current_zoom = Some(zoom);
+        let view = self.calculate_view(window);
+        self.view = Some(view.clone());
+        view
+    }
+
+    /// Returns the `CameraView` for the given window size (pixels),
+    /// at the current zoom.
+    pub fn calculate_view(&self, window: (f32, f32)) -> CameraView {
+        let zoom = self.current_zoom().max(ZOOM_EPSILON);
+        let fit = (window.0 / self.resolution.w)
+            .min(window.1 / self.resolution.h)
+            .max(0.0);
+        let (fit, scale) = if self.pixel_perfect {
+            let fit = fit.floor().max(1.0);
+            (fit, (fit * zoom).round().max(1.0))
+        } else {
+            (fit, fit * zoom)
+        };
+        let content = (self.resolution.w * fit, self.resolution.h * fit);
+        let letterbox = (
+            ((window.0 - content.0) * 0.5).max(0.0),
+            ((window.1 - content.1) * 0.5).max(0.0),
+        );
+        let letterbox = if self.pixel_perfect {
+            (letterbox.0.floor(), letterbox.1.floor())
+        } else {
+            letterbox
+        };
+        CameraView {
+            size: Size::new(window.0 / scale, window.1 / scale),
+            visible: Size::new(
+                (window.0 - letterbox.0 * 2.0) / scale,
+                (window.1 - letterbox.1 * 2.0) / scale,
+            ),
+            scale,
+            letterbox,
+        }
+    }
+
+    /// Snaps the given position to whole screen pixels in pixel-perfect
+    /// mode, using the `CameraView` from the last update.
+    pub(crate) fn snap(&self, position: (f32, f32)) -> (f32, f32) {
+        match (self.pixel_perfect, self.view.as_ref()) {
+            (true, Some(view)) => (
+                (position.0 * view.scale).round() / view.scale,
+                (position.1 * view.scale).round() / view.scale,
+            ),
+            _ => position,
+        }
+    }
+}
+
+/// How the camera's projection is fit into the window.
+#[derive(Clone, Debug)]
+pub struct CameraView {
+    /// The size of the projection (in world units), covering the window.
+    pub size:      Size,
+    /// The size of the area inside the letterbox (in world units).
+    pub visible:   Size,
+    /// Window pixels per world unit.
+    pub scale:     f32,
+    /// The letterbox size in window pixels, on each side of
+    /// the horizontal and vertical axes respectively.
+    pub letterbox: (f32, f32),
+}
+
+fn default_zoom() -> f32 {
+    1.0
+}
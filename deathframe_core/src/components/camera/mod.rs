@@ -0,0 +1,16 @@
+pub mod prelude {
+    pub use super::camera::Camera;
+    pub use super::camera_shake::{CameraShake, CameraShakeAction};
+    pub use super::camera_smoothing::CameraSmoothing;
+    pub use super::camera_zoom::{CameraView, CameraZoom};
+}
+
+#[cfg(test)]
+mod tests;
+
+mod camera;
+mod camera_shake;
+mod camera_smoothing;
+mod camera_zoom;
+
+use super::component_prelude;
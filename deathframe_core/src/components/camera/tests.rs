@@ -0,0 +1,231 @@
+//! Test camera components
+
+use super::component_prelude::*;
+use super::prelude::*;
+use crate::components::prelude::{Size, Transform};
+
+fn viewport() -> Rect {
+    Rect::from(&Size::new(100.0, 50.0))
+}
+
+#[test]
+fn snaps_to_target_on_first_update() {
+    let mut camera = Camera::new().with_smoothing(CameraSmoothing::Lerp {
+        speed: 1.0,
+    });
+    let pos = camera.update(0.1, (0.0, 0.0), Some((30.0, 20.0)), &viewport());
+    assert_eq!(pos, (30.0, 20.0));
+}
+
+#[test]
+fn lerp_moves_towards_target() {
+    let mut camera = Camera::new().with_smoothing(CameraSmoothing::Lerp {
+        speed: 5.0,
+    });
+    camera.update(0.1, (0.0, 0.0), Some((0.0, 0.0)), &viewport());
+    let pos = camera.update(0.1, (0.0, 0.0), Some((10.0, 0.0)), &viewport());
+    assert!(pos.0 > 0.0 && pos.0 < 10.0);
+    for _ in 0 .. 100 {
+        camera.update(0.1, pos, Some((10.0, 0.0)), &viewport());
+    }
+    assert!((camera.position().unwrap().0 - 10.0).abs() < 0.01);
+}
+
+#[test]
+fn spring_settles_on_target() {
+    let mut camera = Camera::new().with_smoothing(CameraSmoothing::Spring {
+        stiffness: 100.0,
+        damping:   20.0,
+    });
+    camera.update(0.016, (0.0, 0.0), Some((0.0, 0.0)), &viewport());
+    for _ in 0 .. 300 {
+        camera.update(0.016, (0.0, 0.0), Some((0.0, 10.0)), &viewport());
+    }
+    assert!((camera.position().unwrap().1 - 10.0).abs() < 0.01);
+}
+
+#[test]
+fn ignores_movement_inside_dead_zone() {
+    let mut camera = Camera::new().with_dead_zone(Size::new(20.0, 20.0));
+    camera.update(0.1, (0.0, 0.0), Some((0.0, 0.0)), &viewport());
+    let pos = camera.update(0.1, (0.0, 0.0), Some((8.0, -8.0)), &viewport());
+    assert_eq!(pos, (0.0, 0.0));
+    let pos = camera.update(0.1, (0.0, 0.0), Some((15.0, 0.0)), &viewport());
+    assert_eq!(pos, (5.0, 0.0));
+}
+
+#[test]
+fn looks_ahead_in_movement_direction() {
+    let mut camera = Camera::new().with_look_ahead((10.0, 0.0));
+    camera.update(0.1, (0.0, 0.0), Some((0.0, 0.0)), &viewport());
+    let pos = camera.update(0.1, (0.0, 0.0), Some((5.0, 0.0)), &viewport());
+    assert_eq!(pos, (15.0, 0.0));
+    let pos = camera.update(0.1, (0.0, 0.0), Some((0.0, 0.0)), &viewport());
+    assert_eq!(pos, (-10.0, 0.0));
+}
+
+#[test]
+fn confines_viewport_to_bounds() {
+    let bounds = Rect {
+        top:    100.0,
+        bottom: 0.0,
+        left:   0.0,
+        right:  200.0,
+    };
+    let mut camera = Camera::new().with_bounds(bounds);
+    let pos = camera.update(0.1, (0.0, 0.0), Some((10.0, 90.0)), &viewport());
+    assert_eq!(pos, (50.0, 75.0));
+}
+
+#[test]
+fn centers_viewport_larger_than_bounds() {
+    let bounds = Rect {
+        top:    40.0,
+        bottom: 0.0,
+        left:   0.0,
+        right:  60.0,
+    };
+    let mut camera = Camera::new().with_bounds(bounds);
+    let pos = camera.update(0.1, (0.0, 0.0), Some((0.0, 0.0)), &viewport());
+    assert_eq!(pos, (30.0, 20.0));
+}
+
+#[test]
+fn shake_trauma_is_clamped_and_decays() {
+    let mut shake = CameraShake::new((4.0, 4.0)).with_decay(0.5);
+    shake.add_trauma(0.8);
+    shake.add_trauma(0.8);
+    assert!(shake.update(1.0).is_some());
+    assert_eq!(shake.trauma(), 0.5);
+    assert!(shake.update(1.0).is_some());
+    assert!(!shake.is_shaking());
+    assert!(shake.update(1.0).is_none());
+}
+
+#[test]
+fn shake_offsets_stay_within_max() {
+    let mut shake = CameraShake::new((4.0, 2.0)).with_max_rotation(0.1);
+    shake.set_trauma(1.0);
+    for _ in 0 .. 60 {
+        shake.add_trauma(1.0);
+        let offsets = shake.update(0.016).unwrap();
+        assert!(offsets.0.abs() <= 4.0 && offsets.1.abs() <= 2.0);
+        assert!(offsets.2.abs() <= 0.1);
+    }
+}
+
+#[test]
+fn shake_stops_immediately() {
+    let mut shake = CameraShake::new((4.0, 4.0));
+    shake.add_trauma(1.0);
+    shake.stop();
+    assert!(shake.update(0.1).is_none());
+}
+
+#[test]
+fn shake_restores_transform_exactly() {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(10.1, -3.3, 0.0);
+    transform.set_rotation_2d(0.3);
+    let rotation = *transform.rotation();
+
+    let mut shake = CameraShake::new((4.0, 4.0)).with_max_rotation(0.2);
+    shake.set_trauma(1.0);
+    for _ in 0 .. 10 {
+        shake.restore(&mut transform);
+        let offsets = shake.update(0.1).unwrap();
+        shake.apply(&mut transform, offsets, None, None);
+    }
+    shake.stop();
+    shake.restore(&mut transform);
+    assert!(shake.update(0.1).is_none());
+
+    assert_eq!(transform.translation().x, 10.1);
+    assert_eq!(transform.translation().y, -3.3);
+    assert_eq!(transform.rotation(), &rotation);
+}
+
+#[test]
+fn shake_applies_on_top_of_position_moved_by_others() {
+    let mut transform = Transform::default();
+    let mut shake = CameraShake::new((4.0, 4.0));
+    shake.set_trauma(1.0);
+    shake.update(0.1);
+    let offsets = shake.update(0.1).unwrap();
+    shake.apply(&mut transform, offsets, None, None);
+
+    shake.restore(&mut transform);
+    transform.set_translation_x(50.0);
+    let offsets = shake.update(0.1).unwrap();
+    shake.apply(&mut transform, offsets, None, None);
+    assert_eq!(transform.translation().x, 50.0 + offsets.0);
+
+    shake.restore(&mut transform);
+    assert_eq!(transform.translation().x, 50.0);
+    assert_eq!(transform.translation().y, 0.0);
+}
+
+fn pixel_perfect_zoom() -> CameraZoom {
+    CameraZoom::new(Size::new(320.0, 180.0)).with_pixel_perfect(true)
+}
+
+#[test]
+fn pixel_perfect_fits_exact_window() {
+    let view = pixel_perfect_zoom().calculate_view((1280.0, 720.0));
+    assert_eq!(view.scale, 4.0);
+    assert_eq!(view.letterbox, (0.0, 0.0));
+    assert_eq!((view.size.w, view.size.h), (320.0, 180.0));
+}
+
+#[test]
+fn pixel_perfect_letterboxes_uneven_window() {
+    let view = pixel_perfect_zoom().calculate_view((1000.0, 700.0));
+    assert_eq!(view.scale, 3.0);
+    assert_eq!(view.letterbox, (20.0, 80.0));
+    assert_eq!((view.visible.w, view.visible.h), (320.0, 180.0));
+}
+
+#[test]
+fn pixel_perfect_scale_is_at_least_one() {
+    let view = pixel_perfect_zoom().calculate_view((200.0, 100.0));
+    assert_eq!(view.scale, 1.0);
+    assert_eq!(view.letterbox, (0.0, 0.0));
+    assert_eq!((view.visible.w, view.visible.h), (200.0, 100.0));
+}
+
+#[test]
+fn pixel_perfect_rounds_zoom_to_integer_scale() {
+    let zoom = pixel_perfect_zoom().with_zoom(1.3);
+    let view = zoom.calculate_view((1280.0, 720.0));
+    assert_eq!(view.scale, 5.0);
+    assert_eq!((view.size.w, view.size.h), (256.0, 144.0));
+}
+
+#[test]
+fn zoom_scales_without_pixel_perfect() {
+    let zoom = CameraZoom::new(Size::new(320.0, 180.0)).with_zoom(2.0);
+    let view = zoom.calculate_view((1000.0, 700.0));
+    assert_eq!(view.scale, 6.25);
+    assert_eq!(view.letterbox, (0.0, 68.75));
+    assert_eq!((view.visible.w, view.visible.h), (160.0, 90.0));
+}
+
+#[test]
+fn zoom_transitions_smoothly() {
+    let mut zoom = CameraZoom::new(Size::new(320.0, 180.0)).with_speed(5.0);
+    zoom.update(0.1, (1280.0, 720.0));
+    zoom.set_zoom(2.0);
+    zoom.update(0.1, (1280.0, 720.0));
+    assert!(zoom.current_zoom() > 1.0 && zoom.current_zoom() < 2.0);
+    for _ in 0 .. 100 {
+        zoom.update(0.1, (1280.0, 720.0));
+    }
+    assert_eq!(zoom.current_zoom(), 2.0);
+}
+
+#[test]
+fn pixel_perfect_snaps_position() {
+    let mut zoom = pixel_perfect_zoom();
+    zoom.update(0.1, (1280.0, 720.0));
+    assert_eq!(zoom.snap((10.3, 0.1)), (10.25, 0.0));
+}
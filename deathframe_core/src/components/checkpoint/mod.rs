@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+
+/// A `Checkpoint` is a trigger area. When an entity with a `Respawnable`,
+/// which uses checkpoints, starts colliding with a checkpoint entity,
+/// the checkpoint is activated and stored in the `Checkpoints` resource.
+/// Respawning entities then respawn at the active checkpoint.
+/// The collision detection happens in the physics crate's
+/// `HandleCheckpointsSystem`; the checkpoint entity needs a `Transform`,
+/// a `Hitbox`, and a `Collidable`.
+#[derive(Component, Clone, Debug, Deserialize)]
+#[storage(HashMapStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Checkpoint {
+    /// Unique identifier of this checkpoint.
+    pub(crate) id:           String,
+    /// Spawn point offset, relative to the checkpoint's position.
+    #[serde(default)]
+    pub(crate) spawn_offset: (f32, f32),
+}
+
+impl Checkpoint {
+    pub fn new<S>(id: S) -> Self
+    where
+        S: ToString,
+    {
+        Self {
+            id:           id.to_string(),
+            spawn_offset: (0.0, 0.0),
+        }
+    }
+
+    pub fn with_spawn_offset(mut self, spawn_offset: (f32, f32)) -> Self {
+        self.spawn_offset = spawn_offset;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the absolute spawn point,
+    /// for the given position of the checkpoint entity.
+    pub fn spawn_point(&self, position: (f32, f32)) -> (f32, f32) {
+        (
+            position.0 + self.spawn_offset.0,
+            position.1 + self.spawn_offset.1,
+        )
+    }
+}
@@ -0,0 +1,30 @@
+//! Test the `Checkpoint` component
+
+use super::*;
+
+#[test]
+fn spawn_point_is_checkpoint_position_without_offset() {
+    let checkpoint = Checkpoint::new("start");
+    assert_eq!(checkpoint.id(), "start");
+    assert_eq!(checkpoint.spawn_point((10.0, -5.0)), (10.0, -5.0));
+}
+
+#[test]
+fn spawn_point_is_offset_from_checkpoint_position() {
+    let checkpoint = Checkpoint::new("start").with_spawn_offset((2.0, -3.0));
+    assert_eq!(checkpoint.spawn_point((10.0, -5.0)), (12.0, -8.0));
+}
+
+#[test]
+fn deserializes_with_default_spawn_offset() {
+    let checkpoint: Checkpoint = ron::de::from_str(r#"(id: "start")"#)
+        .expect("Couldn't deserialize Checkpoint");
+    assert_eq!(checkpoint.id(), "start");
+    assert_eq!(checkpoint.spawn_point((1.0, 1.0)), (1.0, 1.0));
+}
+
+#[test]
+fn rejects_unknown_fields() {
+    let ron = r#"(id: "start", offset: (1.0, 1.0))"#;
+    assert!(ron::de::from_str::<Checkpoint>(ron).is_err());
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+
+/// Entities that have `Confined` and at least a `Transform`,
+/// are confined to the confined `Rect`'s area, with the `ConfineEntitiesSystem`.
+/// How the entity is confined can be set per axis, with a `ConfineMode`.
+#[derive(Component, Builder, Deserialize)]
+#[storage(VecStorage)]
+#[builder(pattern = "owned")]
+#[serde(deny_unknown_fields)]
+pub struct Confined {
+    pub(crate) rect:   Rect,
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) mode_x: ConfineMode,
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) mode_y: ConfineMode,
+}
+
+impl Confined {
+    pub fn builder() -> ConfinedBuilder {
+        ConfinedBuilder::default()
+    }
+
+    /// Set the `ConfineMode` for both axes.
+    pub fn with_mode(mut self, mode: ConfineMode) -> Self {
+        self.mode_x = mode.clone();
+        self.mode_y = mode;
+        self
+    }
+
+    /// Set the `ConfineMode` for the given axis.
+    pub fn with_mode_for(mut self, axis: &Axis, mode: ConfineMode) -> Self {
+        match axis {
+            Axis::X => self.mode_x = mode,
+            Axis::Y => self.mode_y = mode,
+        }
+        self
+    }
+
+    /// Returns the `ConfineMode` for the given axis.
+    pub fn mode(&self, axis: &Axis) -> &ConfineMode {
+        match axis {
+            Axis::X => &self.mode_x,
+            Axis::Y => &self.mode_y,
+        }
+    }
+
+    /// Returns the confined position on the given axis, for an entity
+    /// at `pos` with the given half size (`0.0` without `Size`),
+    /// and the side of the rect which was hit, if any.
+    pub(crate) fn confine(
+        &self,
+        axis: &Axis,
+        pos: f32,
+        half_size: f32,
+    ) -> (f32, Option<Side>) {
+        let (min, max, min_side, max_side) = match axis {
+            Axis::X => {
+                (self.rect.left, self.rect.right, Side::Left, Side::Right)
+            }
+            Axis::Y => {
+                (self.rect.bottom, self.rect.top, Side::Bottom, Side::Top)
+            }
+        };
+        match self.mode(axis) {
+            ConfineMode::Free => (pos, None),
+            ConfineMode::Wrap => {
+                let distance = max - min + half_size * 2.0;
+                if pos - half_size > max {
+                    (pos - distance, Some(max_side))
+                } else if pos + half_size < min {
+                    (pos + distance, Some(min_side))
+                } else {
+                    (pos, None)
+                }
+            }
+            ConfineMode::Clamp | ConfineMode::Bounce | ConfineMode::Kill => {
+                if pos - half_size < min {
+                    (min + half_size, Some(min_side))
+                } else if pos + half_size > max {
+                    (max - half_size, Some(max_side))
+                } else {
+                    (pos, None)
+                }
+            }
+        }
+    }
+}
+
+impl<R> From<R> for Confined
+where
+    R: Into<Rect>,
+{
+    fn from(rect: R) -> Self {
+        Self {
+            rect:   rect.into(),
+            mode_x: ConfineMode::default(),
+            mode_y: ConfineMode::default(),
+        }
+    }
+}
+
+/// How a `Confined` entity is kept inside its `Rect`, on an axis.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub enum ConfineMode {
+    /// Keep the entity inside, by moving it back to the edge (default).
+    Clamp,
+    /// Once the entity has completely left through one edge,
+    /// move it to the opposite edge, from where it re-enters the rect.
+    Wrap,
+    /// Like `Clamp`, but the entity's velocity is also reflected
+    /// at the edge. Needs the physics crate's
+    /// `BounceConfinedEntitiesSystem` to reflect the velocity.
+    Bounce,
+    /// Kill the entity, when it touches the edge.
+    /// Switches the entity's `Lifecycle` to `Death`,
+    /// or deletes the entity, if it has no `Lifecycle`.
+    Kill,
+    /// Don't confine the entity on this axis.
+    Free,
+}
+
+impl Default for ConfineMode {
+    fn default() -> Self {
+        ConfineMode::Clamp
+    }
+}
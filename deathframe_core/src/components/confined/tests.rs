@@ -0,0 +1,49 @@
+//! Test the confined component
+
+use super::*;
+
+fn get_confined(mode: ConfineMode) -> Confined {
+    Confined::from(Rect {
+        top:    10.0,
+        bottom: 0.0,
+        left:   0.0,
+        right:  20.0,
+    })
+    .with_mode(mode)
+}
+
+#[test]
+fn clamps_inside_rect() {
+    let confined = get_confined(ConfineMode::Clamp);
+    assert_eq!(confined.confine(&Axis::X, 5.0, 1.0), (5.0, None));
+    assert_eq!(
+        confined.confine(&Axis::X, 19.5, 1.0),
+        (19.0, Some(Side::Right))
+    );
+    assert_eq!(
+        confined.confine(&Axis::Y, -3.0, 1.0),
+        (1.0, Some(Side::Bottom))
+    );
+}
+
+#[test]
+fn wraps_after_leaving_rect() {
+    let confined = get_confined(ConfineMode::Wrap);
+    assert_eq!(confined.confine(&Axis::X, 20.5, 1.0), (20.5, None));
+    assert_eq!(
+        confined.confine(&Axis::X, 21.5, 1.0),
+        (-0.5, Some(Side::Right))
+    );
+    assert_eq!(
+        confined.confine(&Axis::Y, -1.5, 1.0),
+        (10.5, Some(Side::Bottom))
+    );
+}
+
+#[test]
+fn confines_only_selected_axis() {
+    let confined = get_confined(ConfineMode::Free)
+        .with_mode_for(&Axis::Y, ConfineMode::Bounce);
+    assert_eq!(confined.confine(&Axis::X, 50.0, 0.0), (50.0, None));
+    assert_eq!(confined.confine(&Axis::Y, 50.0, 0.0), (10.0, Some(Side::Top)));
+}
@@ -0,0 +1,64 @@
+use super::component_prelude::*;
+
+/// The direction an entity is facing, on each axis.
+/// Can be used instead of a negative `Transform` scale,
+/// to decide if an entity's hitbox or sprite should be mirrored.
+#[derive(Component, Clone, Debug, Default, Deserialize, PartialEq)]
+#[storage(VecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Facing {
+    #[serde(default)]
+    pub x: FacingX,
+    #[serde(default)]
+    pub y: FacingY,
+}
+
+impl Facing {
+    pub fn new(x: FacingX, y: FacingY) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns `true` if the entity is facing the negative direction
+    /// on the given `Axis` (left for `X`, down for `Y`).
+    pub fn is_flipped(&self, axis: &Axis) -> bool {
+        match axis {
+            Axis::X => self.x == FacingX::Left,
+            Axis::Y => self.y == FacingY::Down,
+        }
+    }
+}
+
+impl From<FacingX> for Facing {
+    fn from(x: FacingX) -> Self {
+        Self {
+            x,
+            y: FacingY::default(),
+        }
+    }
+}
+
+/// The horizontal direction of a `Facing`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum FacingX {
+    Right,
+    Left,
+}
+
+impl Default for FacingX {
+    fn default() -> Self {
+        FacingX::Right
+    }
+}
+
+/// The vertical direction of a `Facing`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum FacingY {
+    Up,
+    Down,
+}
+
+impl Default for FacingY {
+    fn default() -> Self {
+        FacingY::Up
+    }
+}
@@ -0,0 +1,20 @@
+/// What happens to a follower, when the entity it follows is deleted.
+/// See `Follow::with_lost_policy`.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub enum FollowLostPolicy {
+    /// Stay in place, keeping the `Follow` component (default).
+    Stay,
+    /// Delete the follower entity.
+    Delete,
+    /// Remove the follower's `Follow` component.
+    RemoveFollow,
+    /// Switch the follower's `Lifecycle` to the `Death` state.
+    /// Behaves like `Delete`, if the follower has no `Lifecycle`.
+    Death,
+}
+
+impl Default for FollowLostPolicy {
+    fn default() -> Self {
+        FollowLostPolicy::Stay
+    }
+}
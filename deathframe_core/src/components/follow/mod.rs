@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests;
+
+mod follow_lost_policy;
+
+pub use follow_lost_policy::FollowLostPolicy;
+
+use super::component_prelude::*;
+use std::cmp;
+use std::collections::VecDeque;
+
+/// The `Follow` component makes an entity with a `Transform`
+/// _follow_ another entity with a `Transform`.
+/// Gives this component to an entity, which should _follow_ another entity.
+/// By default, the follower snaps directly to the followed entity.
+/// Optionally, the follower can move smoothly, lag behind on a trail,
+/// and ignore movement inside a dead zone.
+/// When the followed entity is deleted, the `FollowSystem` publishes
+/// a `FollowTargetLost` event, and applies the `FollowLostPolicy`.
+#[derive(PartialEq)]
+pub struct Follow {
+    pub(crate) to_follow:    Entity,
+    pub(crate) priority:     i32,
+    pub(crate) offset:       (f32, f32),
+    pub(crate) only_axis:    Option<Axis>,
+    pub(crate) speed:        Option<f32>,
+    pub(crate) max_distance: Option<f32>,
+    pub(crate) dead_zone:    (f32, f32),
+    pub(crate) delay:        Option<f32>,
+    pub(crate) trail:        VecDeque<(f32, (f32, f32))>,
+    pub(crate) elapsed:      f32,
+    pub(crate) lost_policy:  FollowLostPolicy,
+    pub(crate) is_lost:      bool,
+}
+
+impl Follow {
+    /// Creates a new `Follow` component, which should follow the given entity.
+    pub fn new(to_follow: Entity) -> Self {
+        Self {
+            to_follow,
+            priority: 0,
+            offset: (0.0, 0.0),
+            only_axis: None,
+            speed: None,
+            max_distance: None,
+            dead_zone: (0.0, 0.0),
+            delay: None,
+            trail: VecDeque::new(),
+            elapsed: 0.0,
+            lost_policy: FollowLostPolicy::default(),
+            is_lost: false,
+        }
+    }
+
+    /// Set a custom priority for this follower.
+    /// Entities with higher follow priority are moved before
+    /// entities with lower priority.
+    /// Default priority is `0`.
+    /// Followers which follow other followers (like a chain of pets)
+    /// should have a lower priority than the followers they follow.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set a positional offset for this follower.
+    /// So this entity follows the followed entity with an offset.
+    pub fn with_offset(mut self, offset: (f32, f32)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Only follow entity on the given axis, instead of on both axes.
+    pub fn with_only_axis(mut self, only_axis: Axis) -> Self {
+        self.only_axis = Some(only_axis);
+        self
+    }
+
+    /// Follow entity on both axes (default).
+    /// This function only exists to undo `.with_only_axis`.
+    pub fn with_both_axes(mut self) -> Self {
+        self.only_axis = None;
+        self
+    }
+
+    /// Move smoothly towards the followed position,
+    /// instead of snapping to it. A higher `speed` catches up faster.
+    /// Frame-rate independent, the distance to the followed position
+    /// shrinks by the factor `e^(-speed * dt)` every frame.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Never be further away than the given distance
+    /// from the followed entity (plus offset), even when smoothing
+    /// or trailing behind.
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = Some(max_distance);
+        self
+    }
+
+    /// Don't move on an axis, while the followed position is
+    /// within the given distance on that axis.
+    pub fn with_dead_zone(mut self, dead_zone: (f32, f32)) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    /// Follow the position the followed entity had
+    /// the given seconds ago, trailing behind it.
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Set what happens to this follower, when the followed entity
+    /// is deleted. Default is `FollowLostPolicy::Stay`.
+    pub fn with_lost_policy(mut self, lost_policy: FollowLostPolicy) -> Self {
+        self.lost_policy = lost_policy;
+        self
+    }
+
+    /// Returns `true` if the followed entity was deleted.
+    pub fn is_lost(&self) -> bool {
+        self.is_lost
+    }
+
+    /// Returns the follower's new position after `dt` seconds,
+    /// for the given current follower position and followed position.
+    pub(crate) fn update(
+        &mut self,
+        dt: f32,
+        current: (f32, f32),
+        followed: (f32, f32),
+    ) -> (f32, f32) {
+        let target = (followed.0 + self.offset.0, followed.1 + self.offset.1);
+        let goal = self.trail_goal(dt, target);
+        let goal = (
+            dead_zone_axis(current.0, goal.0, self.dead_zone.0),
+            dead_zone_axis(current.1, goal.1, self.dead_zone.1),
+        );
+
+        let pos = if let Some(speed) = self.speed {
+            let t = 1.0 - (-speed.max(0.0) * dt).exp();
+            (
+                current.0 + (goal.0 - current.0) * t,
+                current.1 + (goal.1 - current.1) * t,
+            )
+        } else {
+            goal
+        };
+
+        if let Some(max_distance) = self.max_distance {
+            let diff = (pos.0 - target.0, pos.1 - target.1);
+            let distance = (diff.0 * diff.0 + diff.1 * diff.1).sqrt();
+            if distance > max_distance {
+                let factor = max_distance.max(0.0) / distance;
+                return (target.0 + diff.0 * factor, target.1 + diff.1 * factor);
+            }
+        }
+
+        pos
+    }
+
+    /// Records the target position, and returns the position
+    /// the target had `delay` seconds ago.
+    /// Returns the given target position, if there is no delay.
+    fn trail_goal(&mut self, dt: f32, target: (f32, f32)) -> (f32, f32) {
+        if let Some(delay) = self.delay {
+            self.elapsed += dt;
+            self.trail.push_back((self.elapsed, target));
+            let trail_time = self.elapsed - delay;
+            while self
+                .trail
+                .get(1)
+                .map(|(time, _)| *time <= trail_time)
+                .unwrap_or(false)
+            {
+                self.trail.pop_front();
+            }
+            self.trail.front().map(|(_, pos)| *pos).unwrap_or(target)
+        } else {
+            target
+        }
+    }
+}
+
+fn dead_zone_axis(current: f32, goal: f32, dead_zone: f32) -> f32 {
+    let distance = goal - current;
+    if distance.abs() <= dead_zone {
+        current
+    } else {
+        goal - dead_zone * distance.signum()
+    }
+}
+
+impl Component for Follow {
+    type Storage = VecStorage<Self>;
+}
+
+impl cmp::PartialOrd for Follow {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        other.priority.partial_cmp(&self.priority)
+    }
+}
@@ -0,0 +1,51 @@
+//! Test the follow component
+
+use super::Follow;
+use amethyst::ecs::{World, WorldExt};
+
+fn get_follow() -> Follow {
+    let world = World::new();
+    let entity = world.entities().create();
+    Follow::new(entity)
+}
+
+#[test]
+fn snaps_to_followed_with_offset() {
+    let mut follow = get_follow().with_offset((1.0, -1.0));
+    let pos = follow.update(0.1, (0.0, 0.0), (10.0, 5.0));
+    assert_eq!(pos, (11.0, 4.0));
+}
+
+#[test]
+fn moves_smoothly_with_speed() {
+    let mut follow = get_follow().with_speed(5.0);
+    let pos = follow.update(0.1, (0.0, 0.0), (10.0, 0.0));
+    assert!(pos.0 > 0.0 && pos.0 < 10.0);
+}
+
+#[test]
+fn stays_within_max_distance() {
+    let mut follow = get_follow().with_speed(0.1).with_max_distance(2.0);
+    let pos = follow.update(0.1, (0.0, 0.0), (10.0, 0.0));
+    assert!((pos.0 - 8.0).abs() < 0.001);
+    assert_eq!(pos.1, 0.0);
+}
+
+#[test]
+fn ignores_movement_inside_dead_zone() {
+    let mut follow = get_follow().with_dead_zone((5.0, 0.0));
+    let pos = follow.update(0.1, (0.0, 0.0), (3.0, 3.0));
+    assert_eq!(pos, (0.0, 3.0));
+    let pos = follow.update(0.1, pos, (8.0, 3.0));
+    assert_eq!(pos, (3.0, 3.0));
+}
+
+#[test]
+fn trails_behind_with_delay() {
+    let mut follow = get_follow().with_delay(0.25);
+    let mut pos = (0.0, 0.0);
+    for step in 0 .. 10 {
+        pos = follow.update(0.1, pos, (step as f32, 0.0));
+    }
+    assert_eq!(pos, (6.0, 0.0));
+}
@@ -1,9 +1,33 @@
 use super::HitPoints;
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize)]
 pub enum HealthAction {
     /// _Gain_ health.
     Gain(HitPoints),
     /// _Lose_ health.
     Lose(HitPoints),
+    /// _Gain_ the given percentage (`0.0` to `100.0`) of max health.
+    GainPercent(f32),
+    /// _Lose_ the given percentage (`0.0` to `100.0`) of max health.
+    LosePercent(f32),
+    /// _Set_ health, without exceeding max health.
+    Set(HitPoints),
+    /// _Set_ max health, reducing health if it exceeds the new max.
+    SetMax(HitPoints),
+    /// _Lose_ all health.
+    Kill,
+    /// _Set_ health, only if health is at 0.
+    Revive(HitPoints),
+}
+
+impl HealthAction {
+    /// Returns `true` for actions that deal damage,
+    /// which are ignored while an entity is `Invulnerable`.
+    /// `Kill` is not considered damage.
+    pub fn is_damage(&self) -> bool {
+        match self {
+            HealthAction::Lose(_) | HealthAction::LosePercent(_) => true,
+            _ => false,
+        }
+    }
 }
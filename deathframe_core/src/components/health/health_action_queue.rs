@@ -17,6 +17,30 @@ impl HealthActionQueue {
     pub fn lose(&mut self, hitpoints: HitPoints) {
         self.add_action(HealthAction::Lose(hitpoints))
     }
+
+    pub fn gain_percent(&mut self, percent: f32) {
+        self.add_action(HealthAction::GainPercent(percent))
+    }
+
+    pub fn lose_percent(&mut self, percent: f32) {
+        self.add_action(HealthAction::LosePercent(percent))
+    }
+
+    pub fn set(&mut self, hitpoints: HitPoints) {
+        self.add_action(HealthAction::Set(hitpoints))
+    }
+
+    pub fn set_max(&mut self, hitpoints: HitPoints) {
+        self.add_action(HealthAction::SetMax(hitpoints))
+    }
+
+    pub fn kill(&mut self) {
+        self.add_action(HealthAction::Kill)
+    }
+
+    pub fn revive(&mut self, hitpoints: HitPoints) {
+        self.add_action(HealthAction::Revive(hitpoints))
+    }
 }
 
 impl ActionQueue for HealthActionQueue {
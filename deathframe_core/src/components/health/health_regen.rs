@@ -0,0 +1,71 @@
+use super::component_prelude::*;
+use super::Health;
+
+/// Regenerates the entity's health at a `rate` of hitpoints per second,
+/// through its `HealthActionQueue`, with the `UpdateHealthRegenSystem`.
+/// After the entity takes damage, regeneration pauses for `delay` seconds.
+/// Dead entities don't regenerate.
+#[derive(Component, Clone, Debug, Deserialize)]
+#[storage(VecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct HealthRegen {
+    pub(crate) rate:         f32,
+    #[serde(default)]
+    pub(crate) delay:        f32,
+    #[serde(skip)]
+    pub(crate) since_damage: Option<f32>,
+    #[serde(skip)]
+    pub(crate) last_health:  Option<HitPoints>,
+    #[serde(skip)]
+    pub(crate) remainder:    f32,
+}
+
+impl HealthRegen {
+    /// Create a new `HealthRegen`, gaining `rate` hitpoints per second.
+    pub fn new(rate: f32) -> Self {
+        Self {
+            rate,
+            delay: 0.0,
+            since_damage: None,
+            last_health: None,
+            remainder: 0.0,
+        }
+    }
+
+    /// Pause regeneration for the given seconds, after taking damage.
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the whole hitpoints to gain this frame,
+    /// carrying fractional hitpoints over to the next frame.
+    pub(crate) fn update(&mut self, dt: f32, health: &Health) -> HitPoints {
+        let took_damage = self
+            .last_health
+            .map(|last_health| health.health < last_health)
+            .unwrap_or(false);
+        self.last_health = Some(health.health);
+
+        if took_damage {
+            self.since_damage = Some(0.0);
+        } else if let Some(since_damage) = self.since_damage.as_mut() {
+            *since_damage += dt;
+        }
+
+        let is_delayed = self
+            .since_damage
+            .map(|since_damage| since_damage < self.delay)
+            .unwrap_or(false);
+
+        if is_delayed || !health.is_alive() || health.has_full_health() {
+            self.remainder = 0.0;
+            return 0;
+        }
+
+        let total = self.remainder + self.rate.max(0.0) * dt;
+        let whole = total.floor();
+        self.remainder = total - whole;
+        whole as HitPoints
+    }
+}
@@ -0,0 +1,95 @@
+use super::component_prelude::*;
+use super::health_action::HealthAction;
+
+/// While an entity is `Invulnerable`, it doesn't lose health from
+/// `HealthAction::Lose` actions in the `UpdateHealthSystem`.
+/// Invulnerability lasts for the given `duration` in seconds (game time),
+/// after which the `UpdateInvulnerableSystem` removes this component.
+/// With a `blink_interval` (in seconds), the entity's `HiddenPropagate`
+/// component is toggled at that interval, while it is invulnerable.
+/// The blink doesn't use `Hidden`, so it never shows an entity, which
+/// other systems (like the `EntityLoaderSystem`) want to stay hidden.
+/// An `exempt_action` is still applied once while invulnerable,
+/// for the hit which made the entity invulnerable.
+#[derive(Component, Clone, Debug, Deserialize)]
+#[storage(VecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Invulnerable {
+    pub(crate) duration:       f32,
+    #[serde(default)]
+    pub(crate) blink_interval: Option<f32>,
+    #[serde(skip)]
+    pub(crate) exempt_action:  Option<HealthAction>,
+    #[serde(skip)]
+    pub(crate) elapsed:        f32,
+    #[serde(skip)]
+    pub(crate) is_hiding:      bool,
+}
+
+impl Invulnerable {
+    /// Create a new `Invulnerable` with the given duration in seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            blink_interval: None,
+            exempt_action: None,
+            elapsed: 0.0,
+            is_hiding: false,
+        }
+    }
+
+    /// Still apply the given damage action once, while invulnerable.
+    /// Used for the hit which makes the entity invulnerable, so it isn't
+    /// ignored, if the `UpdateHealthSystem` only applies it
+    /// after the invulnerability started.
+    pub fn with_exempt_action(mut self, action: HealthAction) -> Self {
+        self.exempt_action = Some(action);
+        self
+    }
+
+    /// Toggle the entity's `HiddenPropagate` component at the given interval
+    /// (in seconds), while it is invulnerable.
+    pub fn with_blink_interval(mut self, blink_interval: f32) -> Self {
+        self.blink_interval = Some(blink_interval);
+        self
+    }
+
+    /// Returns `true` while the invulnerability hasn't run out.
+    pub fn is_active(&self) -> bool {
+        self.elapsed < self.duration
+    }
+
+    /// Returns the remaining invulnerability duration in seconds.
+    pub fn remaining(&self) -> f32 {
+        (self.duration - self.elapsed).max(0.0)
+    }
+
+    /// Returns `true` if the given action should be ignored, because
+    /// it deals damage while invulnerable. The exempt action is
+    /// not ignored, but only once.
+    pub(crate) fn ignores_action(&mut self, action: &HealthAction) -> bool {
+        if !self.is_active() || !action.is_damage() {
+            false
+        } else if self.exempt_action.as_ref() == Some(action) {
+            self.exempt_action = None;
+            false
+        } else {
+            true
+        }
+    }
+
+    pub(crate) fn update(&mut self, dt: f32) {
+        self.elapsed += dt;
+    }
+
+    /// Returns `true` if the entity should currently be hidden,
+    /// for the blink effect. Starts hidden, then toggles every interval.
+    pub(crate) fn should_hide(&self) -> bool {
+        match self.blink_interval {
+            Some(interval) if interval > 0.0 && self.is_active() => {
+                (self.elapsed / interval) as u32 % 2 == 0
+            }
+            _ => false,
+        }
+    }
+}
@@ -1,18 +1,28 @@
 pub mod prelude {
     pub use super::health_action::HealthAction;
     pub use super::health_action_queue::HealthActionQueue;
+    pub use super::health_regen::HealthRegen;
+    pub use super::invulnerable::Invulnerable;
+    pub use super::status_effects::{StatusEffect, StatusEffects};
     pub use super::Health;
     pub use super::HitPoints;
 }
 
+#[cfg(test)]
+mod tests;
+
 mod health_action;
 mod health_action_queue;
+mod health_regen;
+mod invulnerable;
+mod status_effects;
 
 use super::component_prelude::{self, *};
+use health_action::HealthAction;
 
 pub type HitPoints = u32;
 
-#[derive(Component, Clone, Deserialize)]
+#[derive(Component, Clone, Debug, PartialEq, Deserialize)]
 #[storage(VecStorage)]
 #[serde(deny_unknown_fields)]
 pub struct Health {
@@ -31,6 +41,55 @@ impl Health {
         self.health = self.health.checked_sub(hp).unwrap_or(0);
     }
 
+    /// Set the hitpoints, without exceeding its max health.
+    pub fn set(&mut self, hp: HitPoints) {
+        self.health = hp.min(self.max_health);
+    }
+
+    /// Set the max health. Health is reduced to the new max health,
+    /// if it would exceed it.
+    pub fn set_max(&mut self, max_hp: HitPoints) {
+        self.max_health = max_hp;
+        self.health = self.health.min(self.max_health);
+    }
+
+    /// Lose all hitpoints.
+    pub fn kill(&mut self) {
+        self.health = 0;
+    }
+
+    /// Set the hitpoints, only if health is at 0.
+    pub fn revive(&mut self, hp: HitPoints) {
+        if !self.is_alive() {
+            self.set(hp);
+        }
+    }
+
+    /// Returns the given percentage (`0.0` to `100.0`) of the max health,
+    /// rounded to whole hitpoints.
+    pub fn percent_of_max(&self, percent: f32) -> HitPoints {
+        (self.max_health as f32 * percent.max(0.0) / 100.0).round()
+            as HitPoints
+    }
+
+    /// Apply the given `HealthAction`.
+    pub fn apply(&mut self, action: &HealthAction) {
+        match action {
+            HealthAction::Gain(hp) => self.gain(*hp),
+            HealthAction::Lose(hp) => self.lose(*hp),
+            HealthAction::GainPercent(percent) => {
+                self.gain(self.percent_of_max(*percent))
+            }
+            HealthAction::LosePercent(percent) => {
+                self.lose(self.percent_of_max(*percent))
+            }
+            HealthAction::Set(hp) => self.set(*hp),
+            HealthAction::SetMax(max_hp) => self.set_max(*max_hp),
+            HealthAction::Kill => self.kill(),
+            HealthAction::Revive(hp) => self.revive(*hp),
+        }
+    }
+
     /// Checks if health is above 0.
     pub fn is_alive(&self) -> bool {
         self.health > 0
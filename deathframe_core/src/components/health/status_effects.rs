@@ -0,0 +1,121 @@
+use super::component_prelude::*;
+
+/// Timed damage-over-time effects (poison, burn, ...) on an entity.
+/// The `UpdateStatusEffectsSystem` deals each effect's damage
+/// through the entity's `HealthActionQueue`, once per tick interval,
+/// until the effect's duration runs out.
+#[derive(Component, Clone, Debug, Default, Deserialize)]
+#[storage(DenseVecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct StatusEffects {
+    #[serde(default)]
+    pub(crate) effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_effect(mut self, effect: StatusEffect) -> Self {
+        self.add(effect);
+        self
+    }
+
+    /// Add the given effect.
+    /// An active effect with the same name is replaced,
+    /// which restarts its duration.
+    pub fn add(&mut self, effect: StatusEffect) {
+        self.remove(&effect.name);
+        self.effects.push(effect);
+    }
+
+    /// Remove the active effect with the given name.
+    pub fn remove(&mut self, name: &str) {
+        self.effects.retain(|effect| effect.name != name);
+    }
+
+    /// Returns `true` if an effect with the given name is active.
+    pub fn has(&self, name: &str) -> bool {
+        self.effects.iter().any(|effect| effect.name == name)
+    }
+
+    /// Returns an iterator over all active effects.
+    pub fn effects(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    /// Advances all effects, removes finished effects,
+    /// and returns the total damage dealt this frame.
+    pub(crate) fn update(&mut self, dt: f32) -> HitPoints {
+        let damage: HitPoints = self
+            .effects
+            .iter_mut()
+            .map(|effect| effect.update(dt))
+            .sum();
+        self.effects.retain(|effect| !effect.is_finished());
+        damage
+    }
+}
+
+/// A single timed damage-over-time effect, see `StatusEffects`.
+/// Deals `damage` every `tick_interval` seconds, for `duration` seconds.
+/// The first tick happens after the first interval.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatusEffect {
+    pub(crate) name:          String,
+    pub(crate) damage:        HitPoints,
+    pub(crate) tick_interval: f32,
+    pub(crate) duration:      f32,
+    #[serde(skip)]
+    pub(crate) elapsed:       f32,
+    #[serde(skip)]
+    pub(crate) ticks:         u32,
+}
+
+impl StatusEffect {
+    pub fn new<S>(
+        name: S,
+        damage: HitPoints,
+        tick_interval: f32,
+        duration: f32,
+    ) -> Self
+    where
+        S: ToString,
+    {
+        Self {
+            name: name.to_string(),
+            damage,
+            tick_interval,
+            duration,
+            elapsed: 0.0,
+            ticks: 0,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the remaining duration in seconds.
+    pub fn remaining(&self) -> f32 {
+        (self.duration - self.elapsed).max(0.0)
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Returns the damage of all ticks, that happened this frame.
+    pub(crate) fn update(&mut self, dt: f32) -> HitPoints {
+        if self.tick_interval <= 0.0 {
+            return 0;
+        }
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        let total_ticks = (self.elapsed / self.tick_interval) as u32;
+        let new_ticks = total_ticks.saturating_sub(self.ticks);
+        self.ticks = total_ticks;
+        self.damage * new_ticks
+    }
+}
@@ -0,0 +1,136 @@
+//! Test health components
+
+use super::prelude::*;
+
+#[test]
+fn is_active_for_duration() {
+    let mut invulnerable = Invulnerable::new(1.0);
+    assert!(invulnerable.is_active());
+    invulnerable.update(0.5);
+    assert!(invulnerable.is_active());
+    assert_eq!(invulnerable.remaining(), 0.5);
+    invulnerable.update(0.5);
+    assert!(!invulnerable.is_active());
+    assert_eq!(invulnerable.remaining(), 0.0);
+}
+
+#[test]
+fn blinks_at_interval() {
+    let mut invulnerable = Invulnerable::new(1.0).with_blink_interval(0.25);
+    assert!(invulnerable.should_hide());
+    invulnerable.update(0.3);
+    assert!(!invulnerable.should_hide());
+    invulnerable.update(0.3);
+    assert!(invulnerable.should_hide());
+    invulnerable.update(0.5);
+    assert!(!invulnerable.should_hide());
+}
+
+#[test]
+fn does_not_blink_without_interval() {
+    let invulnerable = Invulnerable::new(1.0);
+    assert!(!invulnerable.should_hide());
+}
+
+#[test]
+fn ignores_damage_except_exempt_action_once() {
+    let mut invulnerable =
+        Invulnerable::new(1.0).with_exempt_action(HealthAction::Lose(2));
+    assert!(invulnerable.ignores_action(&HealthAction::Lose(1)));
+    assert!(!invulnerable.ignores_action(&HealthAction::Lose(2)));
+    assert!(invulnerable.ignores_action(&HealthAction::Lose(2)));
+    assert!(!invulnerable.ignores_action(&HealthAction::Gain(1)));
+}
+
+fn get_health() -> Health {
+    Health {
+        health:     5,
+        max_health: 10,
+    }
+}
+
+#[test]
+fn set_is_clamped_to_max() {
+    let mut health = get_health();
+    health.apply(&HealthAction::Set(20));
+    assert_eq!(health.health, 10);
+}
+
+#[test]
+fn set_max_reduces_health() {
+    let mut health = get_health();
+    health.apply(&HealthAction::SetMax(3));
+    assert_eq!((health.health, health.max_health), (3, 3));
+}
+
+#[test]
+fn percent_actions_use_max_health() {
+    let mut health = get_health();
+    health.apply(&HealthAction::LosePercent(20.0));
+    assert_eq!(health.health, 3);
+    health.apply(&HealthAction::GainPercent(50.0));
+    assert_eq!(health.health, 8);
+}
+
+#[test]
+fn revive_only_when_dead() {
+    let mut health = get_health();
+    health.apply(&HealthAction::Revive(10));
+    assert_eq!(health.health, 5);
+    health.apply(&HealthAction::Kill);
+    assert!(!health.is_alive());
+    health.apply(&HealthAction::Revive(10));
+    assert_eq!(health.health, 10);
+}
+
+#[test]
+fn regen_gains_at_rate() {
+    let health = get_health();
+    let mut regen = HealthRegen::new(2.0);
+    let gained: HitPoints =
+        (0 .. 4).map(|_| regen.update(0.25, &health)).sum();
+    assert_eq!(gained, 2);
+}
+
+#[test]
+fn regen_pauses_after_damage() {
+    let mut health = get_health();
+    let mut regen = HealthRegen::new(10.0).with_delay(1.0);
+    assert_eq!(regen.update(0.5, &health), 5);
+    health.lose(2);
+    assert_eq!(regen.update(0.5, &health), 0);
+    assert_eq!(regen.update(0.5, &health), 0);
+    assert_eq!(regen.update(0.5, &health), 5);
+}
+
+#[test]
+fn regen_stops_when_dead_or_full() {
+    let mut health = get_health();
+    let mut regen = HealthRegen::new(10.0);
+    health.kill();
+    assert_eq!(regen.update(1.0, &health), 0);
+    health.set(10);
+    assert_eq!(regen.update(1.0, &health), 0);
+}
+
+#[test]
+fn status_effect_ticks_for_duration() {
+    let mut effects = StatusEffects::new()
+        .with_effect(StatusEffect::new("poison", 1, 0.5, 2.0));
+    assert_eq!(effects.update(0.4), 0);
+    assert_eq!(effects.update(0.2), 1);
+    assert_eq!(effects.update(1.0), 2);
+    assert!(effects.has("poison"));
+    assert_eq!(effects.update(1.0), 1);
+    assert!(!effects.has("poison"));
+}
+
+#[test]
+fn adding_status_effect_replaces_same_name() {
+    let mut effects = StatusEffects::new()
+        .with_effect(StatusEffect::new("burn", 1, 1.0, 2.0));
+    effects.update(1.5);
+    effects.add(StatusEffect::new("burn", 2, 1.0, 2.0));
+    assert_eq!(effects.effects().count(), 1);
+    assert_eq!(effects.update(1.0), 2);
+}
@@ -1,5 +1,6 @@
 use super::component_prelude::*;
 use super::lifecycle_state::LifecycleState;
+use std::time::Duration;
 
 /// An entity can have a `Lifecycle`, which lives through different `LifecycleState`s.
 /// After _spawning_, it will stay _alive_ for most of its existence.
@@ -8,8 +9,9 @@ use super::lifecycle_state::LifecycleState;
 #[derive(Component, Default, Clone)]
 #[storage(VecStorage)]
 pub struct Lifecycle {
-    pub state:     LifecycleState,
-    prolong_count: usize,
+    pub state:        LifecycleState,
+    prolong_count:    usize,
+    prolong_duration: Duration,
 }
 
 impl Lifecycle {
@@ -22,14 +24,46 @@ impl Lifecycle {
             .unwrap_or(self.prolong_count);
     }
 
-    pub(crate) fn update(&mut self) {
+    /// Prolong this state for at least `duration` more game time.
+    /// Unlike `prolong`, this is independent of the frame rate.
+    /// Only useful for `Spawn` and `Death` states.
+    pub fn prolong_for(&mut self, duration: Duration) {
+        self.prolong_duration = self
+            .prolong_duration
+            .checked_add(duration)
+            .unwrap_or(self.prolong_duration);
+    }
+
+    /// Update the prolonged frames and duration,
+    /// with the given game time since the last frame.
+    pub(crate) fn update(&mut self, dt: Duration) {
         self.prolong_count =
             self.prolong_count.checked_sub(1).unwrap_or_default();
+        self.prolong_duration =
+            self.prolong_duration.checked_sub(dt).unwrap_or_default();
     }
 
     /// Is the lifecycle manually being prolonged?
     pub(crate) fn is_prolonged(&self) -> bool {
-        self.prolong_count > 0
+        self.prolong_count > 0 || self.prolong_duration > Duration::default()
+    }
+
+    /// Go back to the `Spawn` state, for respawning entities.
+    pub(crate) fn respawn(&mut self) {
+        self.state = LifecycleState::Spawn;
+    }
+
+    /// Switch to the `Death` state, unless the lifecycle is already
+    /// in the `Death` or `Despawn` state.
+    /// Returns the previous state, if the state was switched.
+    pub(crate) fn die(&mut self) -> Option<LifecycleState> {
+        match &self.state {
+            LifecycleState::Death | LifecycleState::Despawn => None,
+            _ => Some(std::mem::replace(
+                &mut self.state,
+                LifecycleState::Death,
+            )),
+        }
     }
 
     /// Cycle to next state.
@@ -5,7 +5,7 @@
 /// Some `LifecycleState` variants can be _prolonged_.
 /// Prolonging means, their current state will be active for
 /// at least N more frames. See the `Lifecycle::prolong` function.
-#[derive(Clone, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
 pub enum LifecycleState {
     /// Default state, switches to `Spawn` ASAP.
     /// Prefer checking for `Spawn` state in game code.
@@ -0,0 +1,44 @@
+use super::component_prelude::*;
+
+/// A time-to-live for entities with a `Lifecycle` (bullets, effects, ...).
+/// Once the `duration` (in seconds of game time) has passed,
+/// the `UpdateLifecycleSystem` moves the entity's `Lifecycle`
+/// from `Alive` to `Death`, after which it will `Despawn` as usual.
+/// Time only counts while the entity is in the `Spawn` or `Alive` state.
+#[derive(Component, Clone, Debug, Deserialize)]
+#[storage(VecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Lifetime {
+    pub(crate) duration: f32,
+    #[serde(skip)]
+    pub(crate) elapsed:  f32,
+}
+
+impl Lifetime {
+    /// Create a new `Lifetime` with the given duration in seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Returns the remaining lifetime in seconds.
+    pub fn remaining(&self) -> f32 {
+        (self.duration - self.elapsed).max(0.0)
+    }
+
+    /// Returns `true` once the lifetime has run out.
+    pub fn is_expired(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    pub(crate) fn update(&mut self, dt: f32) {
+        self.elapsed += dt;
+    }
+
+    /// Restart the lifetime, for respawning entities.
+    pub(crate) fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
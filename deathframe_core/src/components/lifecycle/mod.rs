@@ -1,9 +1,13 @@
 pub mod prelude {
     pub use super::lifecycle::Lifecycle;
     pub use super::lifecycle_state::LifecycleState;
+    pub use super::lifetime::Lifetime;
+    pub use super::respawnable::Respawnable;
 }
 
 mod lifecycle;
 mod lifecycle_state;
+mod lifetime;
+mod respawnable;
 
 use super::component_prelude;
@@ -0,0 +1,56 @@
+use super::component_prelude::*;
+
+/// Makes an entity _respawn_ instead of despawning.
+/// When a `Respawnable` entity's `Lifecycle` leaves the `Death` state,
+/// the `UpdateLifecycleSystem` switches it back to `Spawn`,
+/// instead of `Despawn`.
+/// On respawn, the entity is moved to its spawn point, its `Health`
+/// is reset to its max health, and its `Lifetime` is restarted.
+/// Any other component type `C` is restored to its initial state,
+/// if a `RestoreOnRespawnSystem<C>` is running.
+/// If no spawn point is set, the entity's position when it
+/// first spawned is used.
+/// Entities which use checkpoints activate `Checkpoint`s, and (re)spawn
+/// at the active checkpoint of the `Checkpoints` resource, if there is one.
+#[derive(Component, Clone, Default, Deserialize)]
+#[storage(DenseVecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Respawnable {
+    #[serde(default)]
+    pub(crate) spawn_point:      Option<(f32, f32)>,
+    #[serde(default)]
+    pub(crate) uses_checkpoints: bool,
+}
+
+impl Respawnable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Respawn at the given position.
+    pub fn with_spawn_point(mut self, spawn_point: (f32, f32)) -> Self {
+        self.spawn_point = Some(spawn_point);
+        self
+    }
+
+    /// Activate `Checkpoint`s and respawn at the active checkpoint.
+    pub fn with_checkpoints(mut self, uses_checkpoints: bool) -> Self {
+        self.uses_checkpoints = uses_checkpoints;
+        self
+    }
+
+    /// Returns `true` if this entity activates and respawns at checkpoints.
+    pub fn uses_checkpoints(&self) -> bool {
+        self.uses_checkpoints
+    }
+
+    /// Set the position to respawn at.
+    pub fn set_spawn_point(&mut self, spawn_point: (f32, f32)) {
+        self.spawn_point = Some(spawn_point);
+    }
+
+    /// Returns the position to respawn at, if it is known.
+    pub fn spawn_point(&self) -> Option<(f32, f32)> {
+        self.spawn_point
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+
+/// `Loader` entities can load `Loadable` entities,
+/// whose transforms are within a loading distance
+/// from the `Loader` entity's transform.
+/// Loaded entities are shown, when they are also within the
+/// render distance, which is the loading distance by default.
+///
+/// Entities which are already loaded (or shown) stay loaded (or shown),
+/// until they are further away than the distance plus the `margin`.
+/// This prevents entities on the boundary from flickering
+/// in and out every frame.
+#[derive(Component, Clone, Deserialize)]
+#[storage(VecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct Loader {
+    pub(crate) loading_distance: (f32, f32),
+    pub(crate) render_distance:  Option<(f32, f32)>,
+    #[serde(default)]
+    pub(crate) margin:           (f32, f32),
+}
+
+impl Loader {
+    /// Create a new `Loader` with the given `x` and `y` loading distances.
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            loading_distance: (x, y),
+            render_distance:  None,
+            margin:           (0.0, 0.0),
+        }
+    }
+
+    /// Use a different distance for showing and hiding
+    /// loaded entities, than for loading and unloading them.
+    pub fn with_render_distance(mut self, render_distance: (f32, f32)) -> Self {
+        self.render_distance = Some(render_distance);
+        self
+    }
+
+    /// Keep loaded and shown entities loaded and shown,
+    /// until they are further away than the distances plus this margin.
+    pub fn with_margin(mut self, margin: (f32, f32)) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Returns the distance, at which entities are shown and hidden.
+    pub fn render_distance(&self) -> (f32, f32) {
+        self.render_distance.unwrap_or(self.loading_distance)
+    }
+
+    /// Returns the largest distance on each axis (including margin),
+    /// at which this loader may load or show entities.
+    pub(crate) fn max_distance(&self) -> (f32, f32) {
+        let render_distance = self.render_distance();
+        (
+            self.loading_distance.0.max(render_distance.0) + self.margin.0,
+            self.loading_distance.1.max(render_distance.1) + self.margin.1,
+        )
+    }
+
+    /// Returns `true` if an entity at the given distance per axis
+    /// should be loaded. `is_loaded` is the entity's current state.
+    pub(crate) fn is_in_loading_distance(
+        &self,
+        distance: (f32, f32),
+        is_loaded: bool,
+    ) -> bool {
+        self.is_in_distance(self.loading_distance, distance, is_loaded)
+    }
+
+    /// Returns `true` if an entity at the given distance per axis
+    /// should be shown. `is_shown` is the entity's current state.
+    pub(crate) fn is_in_render_distance(
+        &self,
+        distance: (f32, f32),
+        is_shown: bool,
+    ) -> bool {
+        self.is_in_distance(self.render_distance(), distance, is_shown)
+    }
+
+    fn is_in_distance(
+        &self,
+        max_distance: (f32, f32),
+        distance: (f32, f32),
+        is_active: bool,
+    ) -> bool {
+        let margin = if is_active { self.margin } else { (0.0, 0.0) };
+        distance.0 <= max_distance.0 + margin.0
+            && distance.1 <= max_distance.1 + margin.1
+    }
+}
+
+impl From<(f32, f32)> for Loader {
+    fn from(loading_distance: (f32, f32)) -> Self {
+        Self::new(loading_distance.0, loading_distance.1)
+    }
+}
@@ -0,0 +1,27 @@
+//! Test the loader component
+
+use super::Loader;
+
+#[test]
+fn renders_at_loading_distance_by_default() {
+    let loader = Loader::new(10.0, 5.0);
+    assert_eq!(loader.render_distance(), (10.0, 5.0));
+    assert!(loader.is_in_render_distance((10.0, 5.0), false));
+    assert!(!loader.is_in_render_distance((10.0, 5.5), false));
+}
+
+#[test]
+fn uses_separate_render_distance() {
+    let loader = Loader::new(10.0, 10.0).with_render_distance((4.0, 4.0));
+    assert!(loader.is_in_loading_distance((8.0, 0.0), false));
+    assert!(!loader.is_in_render_distance((8.0, 0.0), false));
+}
+
+#[test]
+fn keeps_loaded_entities_within_margin() {
+    let loader = Loader::new(10.0, 10.0).with_margin((2.0, 2.0));
+    assert!(!loader.is_in_loading_distance((11.0, 0.0), false));
+    assert!(loader.is_in_loading_distance((11.0, 0.0), true));
+    assert!(!loader.is_in_loading_distance((12.5, 0.0), true));
+    assert_eq!(loader.max_distance(), (12.0, 12.0));
+}
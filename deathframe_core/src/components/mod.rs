@@ -2,12 +2,16 @@
 
 pub mod prelude {
     pub use amethyst::core::transform::Transform;
-    pub use amethyst::core::Hidden;
+    pub use amethyst::core::{Hidden, HiddenPropagate};
     pub use amethyst::renderer::{SpriteRender, Transparent};
 
     pub use super::component_helpers::prelude::*;
-    pub use super::confined::Confined;
-    pub use super::follow::Follow;
+    pub use super::attached_to::AttachedTo;
+    pub use super::camera::prelude::*;
+    pub use super::checkpoint::Checkpoint;
+    pub use super::confined::{ConfineMode, Confined};
+    pub use super::facing::{Facing, FacingX, FacingY};
+    pub use super::follow::{Follow, FollowLostPolicy};
     pub use super::health::prelude::*;
     pub use super::lifecycle::prelude::*;
     pub use super::loadable::Loadable;
@@ -42,7 +46,11 @@ pub mod component_prelude {
 /// Doesn't have to be used on components though, can be used for whatever.
 pub mod component_helpers;
 
+mod attached_to;
+mod camera;
+mod checkpoint;
 mod confined;
+mod facing;
 mod follow;
 mod health;
 mod lifecycle;
@@ -0,0 +1,11 @@
+use crate::resources::checkpoints::CheckpointData;
+use amethyst::ecs::Entity;
+
+/// Published when a `Checkpoint` is activated,
+/// by the `activator` entity colliding with the `checkpoint` entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckpointActivated {
+    pub checkpoint: Entity,
+    pub activator:  Entity,
+    pub data:       CheckpointData,
+}
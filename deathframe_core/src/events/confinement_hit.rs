@@ -0,0 +1,11 @@
+use crate::geo::prelude::Side;
+use amethyst::ecs::Entity;
+
+/// Published by the `ConfineEntitiesSystem`, whenever a `Confined`
+/// entity touches (or wraps around) an edge of its confined `Rect`.
+/// The `side` is the side of the confined `Rect`, which was touched.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfinementHit {
+    pub entity: Entity,
+    pub side:   Side,
+}
@@ -0,0 +1,37 @@
+use amethyst::ecs::Entity;
+
+/// Published by the `EntityLoaderSystem`, when a `Loadable` `entity`
+/// is loaded, because it came within the `loader`'s loading distance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityLoaded {
+    pub entity: Entity,
+    pub loader: Entity,
+}
+
+/// Published by the `EntityLoaderSystem`, when a `Loadable` `entity`
+/// is unloaded, because no `Loader` has it in its loading distance.
+/// The `loader` is the `Loader` entity, which kept it loaded last.
+/// It is `None`, if the entity was never loaded by a `Loader`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityUnloaded {
+    pub entity: Entity,
+    pub loader: Option<Entity>,
+}
+
+/// Published by the `EntityLoaderSystem`, when a `Loadable` `entity`
+/// is shown, because it came within the `loader`'s render distance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityShown {
+    pub entity: Entity,
+    pub loader: Entity,
+}
+
+/// Published by the `EntityLoaderSystem`, when a `Loadable` `entity`
+/// is hidden, because no `Loader` has it in its render distance.
+/// The `loader` is the `Loader` entity, which kept it shown last.
+/// It is `None`, if the entity was never shown by a `Loader`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityHidden {
+    pub entity: Entity,
+    pub loader: Option<Entity>,
+}
@@ -0,0 +1,10 @@
+use amethyst::ecs::Entity;
+
+/// Published by the `FollowSystem`, when the `target` entity,
+/// which the `follower` entity follows, was deleted.
+/// Published once per follower, before its `FollowLostPolicy` is applied.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FollowTargetLost {
+    pub follower: Entity,
+    pub target:   Entity,
+}
@@ -0,0 +1,15 @@
+use crate::components::prelude::{Health, HealthAction};
+use amethyst::ecs::Entity;
+
+/// Published by the `UpdateHealthSystem`, whenever an entity's `Health`
+/// is changed by a `HealthAction` from its `HealthActionQueue`.
+/// Also published by the `UpdateLifecycleSystem`, when it resets
+/// a respawning entity's `Health` with a `HealthAction::Set`.
+/// `source` is the action, which caused the change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HealthChanged {
+    pub entity: Entity,
+    pub old:    Health,
+    pub new:    Health,
+    pub source: HealthAction,
+}
@@ -0,0 +1,16 @@
+use crate::components::prelude::LifecycleState;
+use amethyst::ecs::Entity;
+
+/// Published whenever an entity's `Lifecycle` switches
+/// from one `LifecycleState` to another.
+/// Mostly published by the `UpdateLifecycleSystem`, but systems which
+/// kill entities directly publish it too, when they switch a `Lifecycle`
+/// to `Death`: the `ConfineEntitiesSystem` (for `ConfineMode::Kill`),
+/// the `FollowSystem` (for `FollowLostPolicy::Death`), and
+/// the `UpdateAttachmentsSystem` (for children of despawned parents).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LifecycleEvent {
+    pub entity: Entity,
+    pub from:   LifecycleState,
+    pub to:     LifecycleState,
+}
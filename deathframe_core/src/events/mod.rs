@@ -0,0 +1,23 @@
+//! Events published by this crate's systems.
+//! Read them from their respective `EventChannel` resources.
+
+pub mod prelude {
+    pub use super::checkpoint_activated::CheckpointActivated;
+    pub use super::confinement_hit::ConfinementHit;
+    pub use super::entity_loading::{
+        EntityHidden,
+        EntityLoaded,
+        EntityShown,
+        EntityUnloaded,
+    };
+    pub use super::follow_target_lost::FollowTargetLost;
+    pub use super::health_changed::HealthChanged;
+    pub use super::lifecycle_event::LifecycleEvent;
+}
+
+mod checkpoint_activated;
+mod confinement_hit;
+mod entity_loading;
+mod follow_target_lost;
+mod health_changed;
+mod lifecycle_event;
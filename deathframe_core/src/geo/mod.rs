@@ -3,10 +3,12 @@
 
 mod axis;
 mod rect;
+mod side;
 
 pub mod prelude {
     pub use super::axis::prelude::*;
     pub use super::rect::{Rect, RectBuilder};
+    pub use super::side::Side;
     pub use super::Point;
     pub use super::Vector;
 }
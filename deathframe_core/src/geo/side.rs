@@ -0,0 +1,30 @@
+use super::Axis;
+
+/// One of the four sides of a `Rect`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// Returns the `Axis` this side is on
+    /// (`X` for `Left` and `Right`, `Y` for `Top` and `Bottom`).
+    pub fn axis(&self) -> Axis {
+        match self {
+            Side::Left | Side::Right => Axis::X,
+            Side::Top | Side::Bottom => Axis::Y,
+        }
+    }
+
+    /// Returns `true` if this side is in the negative direction
+    /// of its axis (`Left` or `Bottom`).
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Side::Left | Side::Bottom => true,
+            Side::Right | Side::Top => false,
+        }
+    }
+}
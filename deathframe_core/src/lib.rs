@@ -5,6 +5,7 @@ extern crate derive_builder;
 extern crate serde;
 
 pub mod components;
+pub mod events;
 pub mod systems;
 
 pub mod custom_game_data;
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests;
+
+/// Stores the active checkpoint, which `Respawnable` entities,
+/// that use checkpoints, respawn at.
+/// This resource isn't reset between levels, so reloaded levels
+/// can spawn at the active checkpoint. It can be serialized,
+/// to persist the active checkpoint in save files.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Checkpoints {
+    #[serde(default)]
+    active: Option<CheckpointData>,
+}
+
+/// The data of an activated checkpoint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckpointData {
+    /// The `Checkpoint` component's id.
+    pub id:          String,
+    /// The absolute spawn point of the checkpoint.
+    pub spawn_point: (f32, f32),
+}
+
+impl Checkpoints {
+    /// Returns the active checkpoint.
+    pub fn active(&self) -> Option<&CheckpointData> {
+        self.active.as_ref()
+    }
+
+    /// Returns the spawn point of the active checkpoint.
+    pub fn spawn_point(&self) -> Option<(f32, f32)> {
+        self.active.as_ref().map(|active| active.spawn_point)
+    }
+
+    /// Returns `true` if the checkpoint with the given id is active.
+    pub fn is_active(&self, id: &str) -> bool {
+        self.active
+            .as_ref()
+            .map(|active| active.id == id)
+            .unwrap_or(false)
+    }
+
+    /// Activate the given checkpoint.
+    pub fn activate(&mut self, checkpoint: CheckpointData) {
+        self.active = Some(checkpoint);
+    }
+
+    /// Clear the active checkpoint, for example when starting a new game.
+    pub fn clear(&mut self) {
+        self.active = None;
+    }
+}
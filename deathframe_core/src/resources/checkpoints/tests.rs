@@ -0,0 +1,69 @@
+//! Test the `Checkpoints` resource
+
+use super::*;
+
+fn checkpoint_data(id: &str, spawn_point: (f32, f32)) -> CheckpointData {
+    CheckpointData {
+        id: id.to_string(),
+        spawn_point,
+    }
+}
+
+#[test]
+fn has_no_active_checkpoint_by_default() {
+    let checkpoints = Checkpoints::default();
+    assert_eq!(checkpoints.active(), None);
+    assert_eq!(checkpoints.spawn_point(), None);
+    assert!(!checkpoints.is_active("first"));
+}
+
+#[test]
+fn activating_replaces_active_checkpoint() {
+    let mut checkpoints = Checkpoints::default();
+
+    checkpoints.activate(checkpoint_data("first", (1.0, 2.0)));
+    assert!(checkpoints.is_active("first"));
+    assert_eq!(checkpoints.spawn_point(), Some((1.0, 2.0)));
+
+    checkpoints.activate(checkpoint_data("second", (3.0, 4.0)));
+    assert!(!checkpoints.is_active("first"));
+    assert!(checkpoints.is_active("second"));
+    assert_eq!(
+        checkpoints.active(),
+        Some(&checkpoint_data("second", (3.0, 4.0)))
+    );
+}
+
+#[test]
+fn clearing_deactivates_checkpoint() {
+    let mut checkpoints = Checkpoints::default();
+    checkpoints.activate(checkpoint_data("first", (1.0, 2.0)));
+    checkpoints.clear();
+    assert!(!checkpoints.is_active("first"));
+    assert_eq!(checkpoints.spawn_point(), None);
+}
+
+#[test]
+fn survives_serde_round_trip() {
+    let mut checkpoints = Checkpoints::default();
+    checkpoints.activate(checkpoint_data("first", (1.5, -2.0)));
+
+    let serialized = ron::ser::to_string(&checkpoints)
+        .expect("Couldn't serialize Checkpoints");
+    let deserialized: Checkpoints = ron::de::from_str(&serialized)
+        .expect("Couldn't deserialize Checkpoints");
+    assert_eq!(deserialized.active(), checkpoints.active());
+
+    let serialized = ron::ser::to_string(&Checkpoints::default())
+        .expect("Couldn't serialize Checkpoints");
+    let deserialized: Checkpoints = ron::de::from_str(&serialized)
+        .expect("Couldn't deserialize Checkpoints");
+    assert_eq!(deserialized.active(), None);
+}
+
+#[test]
+fn deserializes_without_active_checkpoint() {
+    let checkpoints: Checkpoints =
+        ron::de::from_str("()").expect("Couldn't deserialize Checkpoints");
+    assert_eq!(checkpoints.active(), None);
+}
@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod tests;
+
 use amethyst::ecs::error::Error as SpecsError;
 use amethyst::ecs::{Component, Entity, WriteStorage};
 use std::collections::HashMap;
@@ -16,15 +19,16 @@ impl Default for InsertionAction {
 
 /// This struct accumulates `InsertionAction`s for entities,
 /// and then _inserts_ or _removes_ a certain component from all
-/// entities at once, by calling the `run` method.
-/// Component to insert/remove has to have `Default` implemented.
+/// entities at once, by calling one of the `run` methods.
 /// It can prioritize insertion or removal over the other action.
+/// Actions, which wouldn't change the storage, are skipped;
+/// so a component is never inserted into an entity, which already has it,
+/// and never removed from an entity, which doesn't have it.
 /// Used with `EntityLoaderSystem`.
 #[derive(Default)]
 pub struct EntityComponentInserter {
     prioritize_action: InsertionAction,
     actions:           HashMap<Entity, InsertionAction>,
-    prev_actions:      Option<HashMap<Entity, InsertionAction>>,
 }
 
 impl EntityComponentInserter {
@@ -37,15 +41,10 @@ impl EntityComponentInserter {
         self
     }
 
-    /// If caching logic should be used.
-    /// The caching is pretty bad, and seems to slow things down,
-    /// rather than speed stuff up. Not recommended.
-    pub fn with_cache(mut self, use_cache: bool) -> Self {
-        if use_cache {
-            self.prev_actions = Some(Default::default());
-        } else {
-            self.prev_actions = None;
-        }
+    /// Does nothing. Redundant actions are always skipped,
+    /// by checking the storage's contents on `run`.
+    #[deprecated(note = "redundant actions are always skipped")]
+    pub fn with_cache(self, _use_cache: bool) -> Self {
         self
     }
 
@@ -89,31 +88,45 @@ impl EntityComponentInserter {
     where
         C: Component + Default,
     {
-        for (entity, action) in self.actions.drain() {
-            let prev_action = self
-                .prev_actions
-                .as_ref()
-                .and_then(|prev| prev.get(&entity));
-
-            match (action, prev_action) {
-                // Do nothing, if the new action is the same one as previously.
-                (InsertionAction::Insert, Some(InsertionAction::Insert)) => (),
-                (InsertionAction::Remove, Some(InsertionAction::Remove)) => (),
+        self.run_with(storage, C::default)
+    }
 
-                (action, _) => {
-                    match &action {
-                        InsertionAction::Insert => {
-                            storage.insert(entity, C::default())?;
-                        }
-                        InsertionAction::Remove => {
-                            storage.remove(entity);
-                        }
-                    }
+    /// Run all `InsertionAction`s at once.
+    /// When inserting, inserts a clone of the given prototype component.
+    pub fn run_with_prototype<C>(
+        &mut self,
+        storage: &mut WriteStorage<C>,
+        prototype: &C,
+    ) -> Result<(), SpecsError>
+    where
+        C: Component + Clone,
+    {
+        self.run_with(storage, || prototype.clone())
+    }
 
-                    if let Some(prev_actions) = self.prev_actions.as_mut() {
-                        prev_actions.insert(entity, action);
-                    }
+    /// Run all `InsertionAction`s at once.
+    /// When inserting, inserts the component returned by the given
+    /// factory closure. The closure is only called for entities,
+    /// which don't have the component yet.
+    pub fn run_with<C, F>(
+        &mut self,
+        storage: &mut WriteStorage<C>,
+        mut factory: F,
+    ) -> Result<(), SpecsError>
+    where
+        C: Component,
+        F: FnMut() -> C,
+    {
+        for (entity, action) in self.actions.drain() {
+            let has_component = storage.contains(entity);
+            match action {
+                InsertionAction::Insert if !has_component => {
+                    storage.insert(entity, factory())?;
+                }
+                InsertionAction::Remove if has_component => {
+                    storage.remove(entity);
                 }
+                _ => (),
             }
         }
 
@@ -0,0 +1,166 @@
+//! Test the entity component inserter
+
+use super::{EntityComponentInserter, InsertionAction};
+use amethyst::ecs::storage::ComponentEvent;
+use amethyst::ecs::{
+    Builder,
+    Component,
+    DenseVecStorage,
+    Entity,
+    FlaggedStorage,
+    ReaderId,
+    World,
+    WorldExt,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Marker(u32);
+
+impl Component for Marker {
+    type Storage = FlaggedStorage<Self, DenseVecStorage<Self>>;
+}
+
+#[derive(Default)]
+struct Flag;
+
+impl Component for Flag {
+    type Storage = DenseVecStorage<Self>;
+}
+
+fn setup(amount: usize) -> (World, Vec<Entity>, ReaderId<ComponentEvent>) {
+    let mut world = World::new();
+    world.register::<Marker>();
+    world.register::<Flag>();
+    let entities = (0 .. amount)
+        .map(|_| world.create_entity().build())
+        .collect();
+    let reader_id = world.write_storage::<Marker>().register_reader();
+    (world, entities, reader_id)
+}
+
+/// Returns the amount of `Marker` storage events since the last call.
+fn count_events(
+    world: &World,
+    reader_id: &mut ReaderId<ComponentEvent>,
+) -> usize {
+    world.read_storage::<Marker>().channel().read(reader_id).count()
+}
+
+#[test]
+fn inserts_default_component() {
+    let (world, entities, _) = setup(2);
+    let mut inserter = EntityComponentInserter::default();
+    inserter.insert(entities[0]);
+    inserter.remove(entities[1]);
+    let mut storage = world.write_storage::<Flag>();
+    inserter.run(&mut storage).unwrap();
+    assert!(storage.contains(entities[0]));
+    assert!(!storage.contains(entities[1]));
+}
+
+#[test]
+fn inserts_from_factory() {
+    let (world, entities, _) = setup(3);
+    let mut inserter = EntityComponentInserter::default();
+    entities.iter().for_each(|&entity| inserter.insert(entity));
+    let mut next = 0;
+    inserter
+        .run_with(&mut world.write_storage::<Marker>(), || {
+            next += 1;
+            Marker(next)
+        })
+        .unwrap();
+    assert_eq!(next, 3);
+    let storage = world.read_storage::<Marker>();
+    assert!(entities.iter().all(|&entity| storage.contains(entity)));
+}
+
+#[test]
+fn inserts_clone_of_prototype() {
+    let (world, entities, _) = setup(1);
+    let mut inserter = EntityComponentInserter::default();
+    inserter.insert(entities[0]);
+    inserter
+        .run_with_prototype(&mut world.write_storage::<Marker>(), &Marker(7))
+        .unwrap();
+    assert_eq!(
+        world.read_storage::<Marker>().get(entities[0]),
+        Some(&Marker(7))
+    );
+}
+
+#[test]
+fn prioritizes_action() {
+    let (world, entities, _) = setup(1);
+    let mut inserter = EntityComponentInserter::default()
+        .with_priority(InsertionAction::Remove);
+    inserter.remove(entities[0]);
+    inserter.insert(entities[0]);
+    let mut storage = world.write_storage::<Flag>();
+    inserter.run(&mut storage).unwrap();
+    assert!(!storage.contains(entities[0]));
+}
+
+#[test]
+fn skips_redundant_actions() {
+    let (world, entities, mut reader_id) = setup(2);
+    let mut inserter = EntityComponentInserter::default();
+    let mut calls = 0;
+
+    for _ in 0 .. 2 {
+        inserter.insert(entities[0]);
+        inserter.remove(entities[1]);
+        inserter
+            .run_with(&mut world.write_storage::<Marker>(), || {
+                calls += 1;
+                Marker(0)
+            })
+            .unwrap();
+    }
+
+    assert_eq!(calls, 1);
+    assert_eq!(count_events(&world, &mut reader_id), 1);
+}
+
+#[test]
+fn follows_external_storage_changes() {
+    let (world, entities, _) = setup(1);
+    let mut inserter = EntityComponentInserter::default();
+    inserter.insert(entities[0]);
+    inserter.run(&mut world.write_storage::<Flag>()).unwrap();
+
+    world.write_storage::<Flag>().remove(entities[0]);
+
+    inserter.insert(entities[0]);
+    inserter.run(&mut world.write_storage::<Flag>()).unwrap();
+    assert!(world.read_storage::<Flag>().contains(entities[0]));
+}
+
+#[test]
+fn only_changes_toggled_entities() {
+    const AMOUNT: usize = 1_000;
+    const FRAMES: usize = 10;
+
+    let (world, entities, mut reader_id) = setup(AMOUNT);
+    let mut inserter = EntityComponentInserter::default();
+
+    for frame in 0 .. FRAMES {
+        // Only the first few entities toggle every frame,
+        // all others keep their component.
+        for (i, &entity) in entities.iter().enumerate() {
+            if i < 10 && frame % 2 == 1 {
+                inserter.remove(entity);
+            } else {
+                inserter.insert(entity);
+            }
+        }
+        let mut storage = world.write_storage::<Marker>();
+        inserter.run_with_prototype(&mut storage, &Marker(0)).unwrap();
+    }
+
+    // Initial insertion, then 10 entities removed or inserted per frame.
+    assert_eq!(
+        count_events(&world, &mut reader_id),
+        AMOUNT + (FRAMES - 1) * 10
+    );
+}
@@ -2,6 +2,9 @@ use super::system_prelude::*;
 
 /// This system confines all entities with `Transform` and `Confined`
 /// to the rect defined in `Confined`, taking `Size` into account.
+/// Each axis is confined with its `ConfineMode`.
+/// Publishes a `ConfinementHit` event, whenever an entity
+/// touches (or wraps around) an edge.
 #[derive(Default)]
 pub struct ConfineEntitiesSystem;
 
@@ -12,6 +15,9 @@ impl<'a> System<'a> for ConfineEntitiesSystem {
         WriteStorage<'a, Transform>,
         ReadStorage<'a, Size>,
         ReadStorage<'a, Unloaded>,
+        WriteStorage<'a, Lifecycle>,
+        Write<'a, EventChannel<ConfinementHit>>,
+        Write<'a, EventChannel<LifecycleEvent>>,
     );
 
     fn run(
@@ -22,46 +28,56 @@ impl<'a> System<'a> for ConfineEntitiesSystem {
             mut transforms,
             sizes,
             unloaded_store,
+            mut lifecycle_store,
+            mut confinement_hit_channel,
+            mut lifecycle_event_channel,
         ): Self::SystemData,
     ) {
-        for (_, confined, transform, size_opt, _) in (
+        for (entity, confined, transform, size_opt, lifecycle_opt, _) in (
             &entities,
             &confined_store,
             &mut transforms,
             sizes.maybe(),
+            (&mut lifecycle_store).maybe(),
             !&unloaded_store,
         )
             .join()
         {
-            let pos = {
-                let trans = transform.translation();
-                Point::new(trans.x, trans.y)
-            };
-            if let Some(size) = size_opt {
-                let rect = Rect::from(size).with_offset(&pos);
-                let half_size = size.half();
-                if rect.left < confined.rect.left {
-                    transform
-                        .set_translation_x(confined.rect.left + half_size.w);
-                }
-                if rect.right > confined.rect.right {
-                    transform
-                        .set_translation_x(confined.rect.right - half_size.w);
-                }
-                if rect.bottom < confined.rect.bottom {
-                    transform
-                        .set_translation_y(confined.rect.bottom + half_size.h);
+            let half_size =
+                size_opt.map(Size::half).unwrap_or(Size::new(0.0, 0.0));
+            let mut should_kill = false;
+
+            for axis in Axis::iter() {
+                let pos = {
+                    let trans = transform.translation();
+                    match axis {
+                        Axis::X => trans.x,
+                        Axis::Y => trans.y,
+                    }
+                };
+                let (confined_pos, side_opt) =
+                    confined.confine(&axis, pos, *(&half_size).by_axis(&axis));
+                if confined_pos != pos {
+                    match axis {
+                        Axis::X => transform.set_translation_x(confined_pos),
+                        Axis::Y => transform.set_translation_y(confined_pos),
+                    };
                 }
-                if rect.top > confined.rect.top {
-                    transform
-                        .set_translation_y(confined.rect.top - half_size.h);
+                if let Some(side) = side_opt {
+                    if confined.mode(&axis) == &ConfineMode::Kill {
+                        should_kill = true;
+                    }
+                    confinement_hit_channel
+                        .single_write(ConfinementHit { entity, side });
                 }
-            } else {
-                transform.set_translation_x(
-                    pos.x.min(confined.rect.right).max(confined.rect.left),
-                );
-                transform.set_translation_y(
-                    pos.y.min(confined.rect.top).max(confined.rect.bottom),
+            }
+
+            if should_kill {
+                kill_entity(
+                    entity,
+                    lifecycle_opt,
+                    &entities,
+                    &mut lifecycle_event_channel,
                 );
             }
         }
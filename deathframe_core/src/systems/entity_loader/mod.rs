@@ -0,0 +1,349 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use crate::resources::entity_component_inserter::InsertionAction;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+/// The default width and height of a chunk.
+const DEFAULT_CHUNK_SIZE: (f32, f32) = (256.0, 256.0);
+
+/// The largest chunk coordinate on each axis. Chunk ranges are clamped
+/// to this, so huge or infinite distances can't overflow the `i32` cast.
+const MAX_CHUNK_COORD: f32 = (i32::MAX / 2) as f32;
+
+/// `Loadable` entities, which cover more chunks than this,
+/// aren't bucketed into chunks, but are checked by every `Loader`.
+const MAX_CHUNKS_PER_LOADABLE: i64 = 64;
+
+/// The `EntityLoaderSystem` handles the loading and unloading
+/// of entities. Entities with the `Loader` component load
+/// entities when they are in range with `Loadable` entities,
+/// and `Loadable` entities are unloaded when _no_ `Loader` entities
+/// are in range. Loaded entities are hidden, when _no_ `Loader`
+/// entities have them in their render distance.
+///
+/// `Loadable` entities are bucketed into a grid of chunks every frame,
+/// so each `Loader` only checks the entities in its nearby chunks.
+/// Very large `Loadable` entities are checked by every `Loader`.
+/// If there are no `Loader` entities, nothing is loaded or unloaded.
+///
+/// Publishes `EntityLoaded`, `EntityUnloaded`, `EntityShown`,
+/// and `EntityHidden` events, whenever an entity's state changes.
+///
+/// The system keeps track of which entities it loaded and showed,
+/// and only inserts or removes `Unloaded` and `Hidden`, when that
+/// state changes. So a `Hidden` component inserted by other systems,
+/// for example for an attached child of a hidden parent, is left alone.
+/// An entity's state is taken from its components,
+/// the first time the system sees it.
+pub struct EntityLoaderSystem {
+    chunk_size:           (f32, f32),
+    entity_unloader:      EntityComponentInserter,
+    entity_loader_hidden: EntityComponentInserter,
+    tracked:              HashSet<Entity>,
+    loaded_by:            HashMap<Entity, Entity>,
+    shown_by:             HashMap<Entity, Entity>,
+}
+
+impl EntityLoaderSystem {
+    /// Does nothing. See `EntityComponentInserter::with_cache`.
+    #[deprecated(note = "redundant actions are always skipped")]
+    pub fn with_cache(self, _use_cache: bool) -> Self {
+        self
+    }
+
+    /// Set the width and height of the chunks, which `Loadable` entities
+    /// are bucketed into. Smaller chunks mean fewer entities are checked
+    /// per `Loader`, but large entities are put into more chunks.
+    /// Default is `(256.0, 256.0)`.
+    pub fn with_chunk_size(mut self, chunk_size: (f32, f32)) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Returns the range of chunk coordinates on one axis,
+    /// which the area from `min` to `max` covers.
+    /// The range is clamped to `MAX_CHUNK_COORD`.
+    fn chunk_range(&self, min: f32, max: f32, axis: &Axis) -> (i32, i32) {
+        let chunk_size = match axis {
+            Axis::X => self.chunk_size.0,
+            Axis::Y => self.chunk_size.1,
+        }
+        .max(1.0);
+        let to_coord = |value: f32| {
+            (value / chunk_size)
+                .floor()
+                .max(-MAX_CHUNK_COORD)
+                .min(MAX_CHUNK_COORD) as i32
+        };
+        (to_coord(min), to_coord(max))
+    }
+}
+
+/// Returns the amount of chunks in the given column and row ranges.
+fn chunk_count(columns: (i32, i32), rows: (i32, i32)) -> i64 {
+    (columns.1 as i64 - columns.0 as i64 + 1)
+        * (rows.1 as i64 - rows.0 as i64 + 1)
+}
+
+impl Default for EntityLoaderSystem {
+    fn default() -> Self {
+        Self {
+            chunk_size:           DEFAULT_CHUNK_SIZE,
+            entity_unloader:      EntityComponentInserter::default()
+                .with_priority(InsertionAction::Remove),
+            entity_loader_hidden: EntityComponentInserter::default()
+                .with_priority(InsertionAction::Remove),
+            tracked:              HashSet::new(),
+            loaded_by:            HashMap::new(),
+            shown_by:             HashMap::new(),
+        }
+    }
+}
+
+/// A `Loadable` entity's data, collected for the current frame.
+struct LoadableData<'a> {
+    entity:   Entity,
+    pos:      (f32, f32),
+    size:     (f32, f32),
+    loadable: &'a Loadable,
+}
+
+impl<'a> LoadableData<'a> {
+    /// Returns the distance per axis from the given position
+    /// to this entity's edges, for rendering and for loading.
+    /// The loading distance subtracts the `Loadable`'s padding.
+    fn distances_to(&self, pos: (f32, f32)) -> ((f32, f32), (f32, f32)) {
+        let padding = (
+            self.loadable.padding.0.unwrap_or(0.0),
+            self.loadable.padding.1.unwrap_or(0.0),
+        );
+        let render_distance = (
+            (pos.0 - self.pos.0).abs() - self.size.0 * 0.5,
+            (pos.1 - self.pos.1).abs() - self.size.1 * 0.5,
+        );
+        let loading_distance =
+            (render_distance.0 - padding.0, render_distance.1 - padding.1);
+        (loading_distance, render_distance)
+    }
+
+    /// Returns the half extent of this entity, including its padding.
+    fn half_extent(&self) -> (f32, f32) {
+        (
+            self.size.0 * 0.5 + self.loadable.padding.0.unwrap_or(0.0).max(0.0),
+            self.size.1 * 0.5 + self.loadable.padding.1.unwrap_or(0.0).max(0.0),
+        )
+    }
+}
+
+impl<'a> System<'a> for EntityLoaderSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, Size>,
+        ReadStorage<'a, Loader>,
+        ReadStorage<'a, Loadable>,
+        WriteStorage<'a, Unloaded>,
+        WriteStorage<'a, Hidden>,
+        Write<'a, EventChannel<EntityLoaded>>,
+        Write<'a, EventChannel<EntityUnloaded>>,
+        Write<'a, EventChannel<EntityShown>>,
+        Write<'a, EventChannel<EntityHidden>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            transforms,
+            sizes,
+            loaders,
+            loadables,
+            mut unloadeds,
+            mut hiddens,
+            mut loaded_channel,
+            mut unloaded_channel,
+            mut shown_channel,
+            mut hidden_channel,
+        ): Self::SystemData,
+    ) {
+        let loaders_data = (&entities, &loaders, &transforms)
+            .join()
+            .map(|(loader_entity, loader, loader_transform)| {
+                let trans = loader_transform.translation();
+                (loader_entity, loader, (trans.x, trans.y))
+            })
+            .collect::<Vec<_>>();
+        if loaders_data.is_empty() {
+            return;
+        }
+
+        let loadables_data = (&entities, &transforms, sizes.maybe(), &loadables)
+            .join()
+            .map(|(entity, transform, size_opt, loadable)| {
+                let trans = transform.translation();
+                LoadableData {
+                    entity,
+                    pos: (trans.x, trans.y),
+                    size: size_opt.map(|s| (s.w, s.h)).unwrap_or((0.0, 0.0)),
+                    loadable,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut chunks: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        let mut unbucketed: Vec<usize> = Vec::new();
+        for (idx, data) in loadables_data.iter().enumerate() {
+            let half_extent = data.half_extent();
+            let columns = self.chunk_range(
+                data.pos.0 - half_extent.0,
+                data.pos.0 + half_extent.0,
+                &Axis::X,
+            );
+            let rows = self.chunk_range(
+                data.pos.1 - half_extent.1,
+                data.pos.1 + half_extent.1,
+                &Axis::Y,
+            );
+            if chunk_count(columns, rows) > MAX_CHUNKS_PER_LOADABLE {
+                unbucketed.push(idx);
+                continue;
+            }
+            for column in columns.0 ..= columns.1 {
+                for row in rows.0 ..= rows.1 {
+                    chunks.entry((column, row)).or_default().push(idx);
+                }
+            }
+        }
+
+        let prev_tracked = mem::take(&mut self.tracked);
+        let prev_loaded_by = mem::take(&mut self.loaded_by);
+        let prev_shown_by = mem::take(&mut self.shown_by);
+
+        // Whether each loadable was loaded and shown before this frame.
+        let was_loaded = loadables_data
+            .iter()
+            .map(|data| {
+                if prev_tracked.contains(&data.entity) {
+                    prev_loaded_by.contains_key(&data.entity)
+                } else {
+                    !unloadeds.contains(data.entity)
+                }
+            })
+            .collect::<Vec<_>>();
+        let was_shown = loadables_data
+            .iter()
+            .map(|data| {
+                if prev_tracked.contains(&data.entity) {
+                    prev_shown_by.contains_key(&data.entity)
+                } else {
+                    !hiddens.contains(data.entity)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The first `Loader` entity, which has the loadable
+        // in its loading or render distance, per loadable.
+        let mut loaded_by: Vec<Option<Entity>> =
+            vec![None; loadables_data.len()];
+        let mut shown_by: Vec<Option<Entity>> =
+            vec![None; loadables_data.len()];
+
+        for (loader_entity, loader, loader_pos) in loaders_data {
+            let max_distance = loader.max_distance();
+            let columns = self.chunk_range(
+                loader_pos.0 - max_distance.0,
+                loader_pos.0 + max_distance.0,
+                &Axis::X,
+            );
+            let rows = self.chunk_range(
+                loader_pos.1 - max_distance.1,
+                loader_pos.1 + max_distance.1,
+                &Axis::Y,
+            );
+
+            // If the loader's range covers more chunks than are occupied,
+            // iterate the occupied chunks instead of the whole range.
+            let in_range: Vec<&Vec<usize>> =
+                if chunk_count(columns, rows) > chunks.len() as i64 {
+                    chunks
+                        .iter()
+                        .filter(|((column, row), _)| {
+                            (columns.0 ..= columns.1).contains(column)
+                                && (rows.0 ..= rows.1).contains(row)
+                        })
+                        .map(|(_, chunk)| chunk)
+                        .collect()
+                } else {
+                    (columns.0 ..= columns.1)
+                        .flat_map(|column| {
+                            (rows.0 ..= rows.1).map(move |row| (column, row))
+                        })
+                        .filter_map(|coords| chunks.get(&coords))
+                        .collect()
+                };
+
+            let mut checked = HashSet::new();
+            for &idx in in_range.into_iter().flatten().chain(&unbucketed) {
+                if !checked.insert(idx) {
+                    continue;
+                }
+                let data = &loadables_data[idx];
+                let (loading_distance, render_distance) =
+                    data.distances_to(loader_pos);
+                if loader
+                    .is_in_loading_distance(loading_distance, was_loaded[idx])
+                {
+                    loaded_by[idx].get_or_insert(loader_entity);
+                }
+                if loader.is_in_render_distance(render_distance, was_shown[idx])
+                {
+                    shown_by[idx].get_or_insert(loader_entity);
+                }
+            }
+        }
+
+        for (idx, data) in loadables_data.iter().enumerate() {
+            let entity = data.entity;
+            self.tracked.insert(entity);
+
+            if let Some(loader) = loaded_by[idx] {
+                self.loaded_by.insert(entity, loader);
+                if !was_loaded[idx] {
+                    self.entity_unloader.remove(entity);
+                    loaded_channel
+                        .single_write(EntityLoaded { entity, loader });
+                }
+            } else if was_loaded[idx] {
+                self.entity_unloader.insert(entity);
+                unloaded_channel.single_write(EntityUnloaded {
+                    entity,
+                    loader: prev_loaded_by.get(&entity).copied(),
+                });
+            }
+
+            if let Some(loader) = shown_by[idx] {
+                self.shown_by.insert(entity, loader);
+                if !was_shown[idx] {
+                    self.entity_loader_hidden.remove(entity);
+                    shown_channel.single_write(EntityShown { entity, loader });
+                }
+            } else if was_shown[idx] {
+                self.entity_loader_hidden.insert(entity);
+                hidden_channel.single_write(EntityHidden {
+                    entity,
+                    loader: prev_shown_by.get(&entity).copied(),
+                });
+            }
+        }
+
+        self.entity_unloader
+            .run(&mut unloadeds)
+            .expect("EntityLoader didn't load entities successfully");
+        self.entity_loader_hidden
+            .run(&mut hiddens)
+            .expect("EntityLoader didn't show/hide entities successfully");
+    }
+}
@@ -0,0 +1,208 @@
+//! Test loading and unloading of the `EntityLoaderSystem`
+
+use super::*;
+use amethyst::ecs::{Builder, RunNow, WorldExt};
+
+fn setup() -> (World, EntityLoaderSystem) {
+    let mut world = World::new();
+    let mut system =
+        EntityLoaderSystem::default().with_chunk_size((10.0, 10.0));
+    System::setup(&mut system, &mut world);
+    (world, system)
+}
+
+fn create_at(world: &mut World, x: f32) -> Entity {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(x, 0.0, 0.0);
+    world.create_entity().with(transform).build()
+}
+
+fn create_loadable(world: &mut World, x: f32) -> Entity {
+    let entity = create_at(world, x);
+    world
+        .write_storage::<Loadable>()
+        .insert(entity, Loadable::default())
+        .unwrap();
+    world
+        .write_storage::<Size>()
+        .insert(entity, Size::new(2.0, 2.0))
+        .unwrap();
+    entity
+}
+
+fn create_loader(world: &mut World, x: f32, loader: Loader) -> Entity {
+    let entity = create_at(world, x);
+    world.write_storage::<Loader>().insert(entity, loader).unwrap();
+    entity
+}
+
+fn move_to(world: &World, entity: Entity, x: f32) {
+    world
+        .write_storage::<Transform>()
+        .get_mut(entity)
+        .unwrap()
+        .set_translation_x(x);
+}
+
+fn is_loaded(world: &World, entity: Entity) -> bool {
+    !world.read_storage::<Unloaded>().contains(entity)
+}
+
+fn is_shown(world: &World, entity: Entity) -> bool {
+    !world.read_storage::<Hidden>().contains(entity)
+}
+
+#[test]
+fn loads_and_unloads_across_chunk_boundaries() {
+    let (mut world, mut system) = setup();
+    // Only in chunk column 10.
+    let loadable = create_loadable(&mut world, 102.0);
+    let loader = create_loader(&mut world, 0.0, Loader::new(5.0, 5.0));
+
+    system.run_now(&world);
+    assert!(!is_loaded(&world, loadable));
+    assert!(!is_shown(&world, loadable));
+
+    // In chunk column 9, with its distance reaching into column 10.
+    move_to(&world, loader, 98.0);
+    system.run_now(&world);
+    assert!(is_loaded(&world, loadable));
+    assert!(is_shown(&world, loadable));
+
+    move_to(&world, loader, 0.0);
+    system.run_now(&world);
+    assert!(!is_loaded(&world, loadable));
+    assert!(!is_shown(&world, loadable));
+}
+
+#[test]
+fn loads_with_infinite_distance() {
+    let (mut world, mut system) = setup();
+    let loadable = create_loadable(&mut world, 1_000_000.0);
+    let _ = create_loader(
+        &mut world,
+        0.0,
+        Loader::new(std::f32::INFINITY, std::f32::INFINITY),
+    );
+
+    system.run_now(&world);
+    assert!(is_loaded(&world, loadable));
+    assert!(is_shown(&world, loadable));
+}
+
+#[test]
+fn loads_huge_loadables_from_any_chunk() {
+    let (mut world, mut system) = setup();
+    let loadable = create_loadable(&mut world, 0.0);
+    world
+        .write_storage::<Size>()
+        .insert(loadable, Size::new(10_000.0, 2.0))
+        .unwrap();
+    let _ = create_loader(&mut world, 4_000.0, Loader::new(5.0, 5.0));
+
+    system.run_now(&world);
+    assert!(is_loaded(&world, loadable));
+}
+
+/// Returns the events of the given type since the last call.
+fn read_events<E>(world: &World, reader_id: &mut ReaderId<E>) -> Vec<E>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    world
+        .read_resource::<EventChannel<E>>()
+        .read(reader_id)
+        .cloned()
+        .collect()
+}
+
+fn register_reader<E>(world: &World) -> ReaderId<E>
+where
+    E: Send + Sync + 'static,
+{
+    world.write_resource::<EventChannel<E>>().register_reader()
+}
+
+#[test]
+fn publishes_load_events_with_loader() {
+    let (mut world, mut system) = setup();
+    let mut loaded_reader = register_reader::<EntityLoaded>(&world);
+    let mut unloaded_reader = register_reader::<EntityUnloaded>(&world);
+    let mut shown_reader = register_reader::<EntityShown>(&world);
+    let mut hidden_reader = register_reader::<EntityHidden>(&world);
+    let loadable = create_loadable(&mut world, 100.0);
+    let loader = create_loader(&mut world, 0.0, Loader::new(5.0, 5.0));
+
+    system.run_now(&world);
+    assert_eq!(read_events(&world, &mut unloaded_reader), vec![
+        EntityUnloaded {
+            entity: loadable,
+            loader: None,
+        }
+    ]);
+    assert_eq!(read_events(&world, &mut hidden_reader), vec![
+        EntityHidden {
+            entity: loadable,
+            loader: None,
+        }
+    ]);
+
+    move_to(&world, loader, 100.0);
+    system.run_now(&world);
+    assert_eq!(read_events(&world, &mut loaded_reader), vec![
+        EntityLoaded {
+            entity: loadable,
+            loader,
+        }
+    ]);
+    assert_eq!(read_events(&world, &mut shown_reader), vec![EntityShown {
+        entity: loadable,
+        loader,
+    }]);
+
+    move_to(&world, loader, 0.0);
+    system.run_now(&world);
+    assert_eq!(read_events(&world, &mut unloaded_reader), vec![
+        EntityUnloaded {
+            entity: loadable,
+            loader: Some(loader),
+        }
+    ]);
+    assert_eq!(read_events(&world, &mut hidden_reader), vec![
+        EntityHidden {
+            entity: loadable,
+            loader: Some(loader),
+        }
+    ]);
+    assert!(read_events(&world, &mut loaded_reader).is_empty());
+    assert!(read_events(&world, &mut shown_reader).is_empty());
+}
+
+#[test]
+fn doesnt_publish_events_without_state_change() {
+    let (mut world, mut system) = setup();
+    let mut loaded_reader = register_reader::<EntityLoaded>(&world);
+    let mut shown_reader = register_reader::<EntityShown>(&world);
+    let _ = create_loadable(&mut world, 0.0);
+    let _ = create_loader(&mut world, 0.0, Loader::new(5.0, 5.0));
+
+    system.run_now(&world);
+    system.run_now(&world);
+    assert!(read_events(&world, &mut loaded_reader).is_empty());
+    assert!(read_events(&world, &mut shown_reader).is_empty());
+}
+
+#[test]
+fn doesnt_remove_hidden_inserted_by_others() {
+    let (mut world, mut system) = setup();
+    let mut shown_reader = register_reader::<EntityShown>(&world);
+    let loadable = create_loadable(&mut world, 0.0);
+    let _ = create_loader(&mut world, 0.0, Loader::new(5.0, 5.0));
+
+    system.run_now(&world);
+    // Hidden by something else, like the parent of an attached child.
+    world.write_storage::<Hidden>().insert(loadable, Hidden).unwrap();
+    system.run_now(&world);
+    assert!(!is_shown(&world, loadable));
+    assert!(read_events(&world, &mut shown_reader).is_empty());
+}
@@ -1,50 +1,114 @@
 use super::system_prelude::*;
 
+/// Moves entities with `Follow` towards the entities they follow.
+/// When a followed entity is deleted, publishes a `FollowTargetLost`
+/// event once, and applies the follower's `FollowLostPolicy`.
 #[derive(Default)]
 pub struct FollowSystem;
 
 impl<'a> System<'a> for FollowSystem {
     type SystemData = (
         Entities<'a>,
-        ReadStorage<'a, Follow>,
+        Read<'a, Time>,
+        WriteStorage<'a, Follow>,
         WriteStorage<'a, Transform>,
+        WriteStorage<'a, Lifecycle>,
+        Write<'a, EventChannel<FollowTargetLost>>,
+        Write<'a, EventChannel<LifecycleEvent>>,
     );
 
-    fn run(&mut self, (entities, followers, mut transforms): Self::SystemData) {
-        let mut followers_join =
-            (&entities, &followers).join().collect::<Vec<_>>();
-        followers_join.sort_by(|(_, follower_a), (_, follower_b)| {
-            follower_a.partial_cmp(follower_b).unwrap()
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut followers,
+            mut transforms,
+            mut lifecycles,
+            mut follow_target_lost_channel,
+            mut lifecycle_event_channel,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+
+        let mut followers_join = (&entities, &followers)
+            .join()
+            .map(|(entity, follower)| (entity, follower.priority))
+            .collect::<Vec<_>>();
+        followers_join.sort_by(|(_, priority_a), (_, priority_b)| {
+            priority_b.cmp(priority_a)
         });
 
-        for (follower_entity, follower) in followers_join {
-            if let Some(followed_pos) =
-                transforms.get(follower.to_follow).map(|transform| {
-                    let translation = transform.translation();
-                    (translation.x, translation.y)
-                })
-            {
-                if let Some(follower_transform) =
-                    transforms.get_mut(follower_entity)
-                {
-                    let new_pos = (
-                        followed_pos.0 + follower.offset.0,
-                        followed_pos.1 + follower.offset.1,
+        for (follower_entity, _) in followers_join {
+            let follower = match followers.get_mut(follower_entity) {
+                Some(follower) => follower,
+                None => continue,
+            };
+
+            if !entities.is_alive(follower.to_follow) {
+                if !follower.is_lost {
+                    follower.is_lost = true;
+                    follow_target_lost_channel.single_write(
+                        FollowTargetLost {
+                            follower: follower_entity,
+                            target:   follower.to_follow,
+                        },
                     );
-                    match follower.only_axis.as_ref() {
-                        None => {
-                            follower_transform.set_translation_x(new_pos.0);
-                            follower_transform.set_translation_y(new_pos.1);
+                    match follower.lost_policy.clone() {
+                        FollowLostPolicy::Stay => (),
+                        FollowLostPolicy::Delete => {
+                            delete_follower(&entities, follower_entity);
                         }
-                        Some(Axis::X) => {
-                            follower_transform.set_translation_x(new_pos.0);
+                        FollowLostPolicy::RemoveFollow => {
+                            followers.remove(follower_entity);
                         }
-                        Some(Axis::Y) => {
-                            follower_transform.set_translation_y(new_pos.1);
+                        FollowLostPolicy::Death => {
+                            kill_entity(
+                                follower_entity,
+                                lifecycles.get_mut(follower_entity),
+                                &entities,
+                                &mut lifecycle_event_channel,
+                            );
                         }
                     }
                 }
+                continue;
+            }
+            let followed_pos = match transforms.get(follower.to_follow) {
+                Some(transform) => {
+                    let translation = transform.translation();
+                    (translation.x, translation.y)
+                }
+                None => continue,
+            };
+
+            if let Some(follower_transform) =
+                transforms.get_mut(follower_entity)
+            {
+                let current_pos = {
+                    let translation = follower_transform.translation();
+                    (translation.x, translation.y)
+                };
+                let new_pos = follower.update(dt, current_pos, followed_pos);
+                match follower.only_axis.as_ref() {
+                    None => {
+                        follower_transform.set_translation_x(new_pos.0);
+                        follower_transform.set_translation_y(new_pos.1);
+                    }
+                    Some(Axis::X) => {
+                        follower_transform.set_translation_x(new_pos.0);
+                    }
+                    Some(Axis::Y) => {
+                        follower_transform.set_translation_y(new_pos.1);
+                    }
+                }
             }
         }
     }
 }
+
+fn delete_follower(entities: &Entities, follower: Entity) {
+    entities
+        .delete(follower)
+        .expect("Couldn't delete follower of deleted entity");
+}
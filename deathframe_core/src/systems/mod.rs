@@ -6,9 +6,18 @@ pub mod prelude {
     pub use super::follow::FollowSystem;
     pub use super::input_manager::InputManagerSystem;
     pub use super::print_fps::PrintFpsSystem;
+    pub use super::restore_camera_shake::RestoreCameraShakeSystem;
+    pub use super::restore_on_respawn::RestoreOnRespawnSystem;
     pub use super::scale_sprites::ScaleSpritesSystem;
+    pub use super::update_attachments::UpdateAttachmentsSystem;
+    pub use super::update_camera::UpdateCameraSystem;
+    pub use super::update_camera_shake::UpdateCameraShakeSystem;
+    pub use super::update_camera_zoom::UpdateCameraZoomSystem;
     pub use super::update_health::UpdateHealthSystem;
+    pub use super::update_health_regen::UpdateHealthRegenSystem;
+    pub use super::update_invulnerable::UpdateInvulnerableSystem;
     pub use super::update_lifecycle::UpdateLifecycleSystem;
+    pub use super::update_status_effects::UpdateStatusEffectsSystem;
 }
 
 pub mod system_prelude {
@@ -34,6 +43,7 @@ pub mod system_prelude {
     pub use amethyst::ecs::{SystemData, World};
     pub use amethyst::input::InputHandler;
     pub use amethyst::renderer::sprite::{SpriteSheet, SpriteSheetHandle};
+    pub use amethyst::shrev::{EventChannel, ReaderId};
     pub use amethyst::renderer::{
         Camera as AmethystCamera,
         SpriteRender,
@@ -41,8 +51,11 @@ pub mod system_prelude {
     };
 
     pub use crate::components::prelude::*;
+    pub use crate::events::prelude::*;
     pub use crate::geo::prelude::*;
     pub use crate::resources::prelude::*;
+
+    pub(crate) use super::helpers::*;
 }
 
 mod confine_entities;
@@ -50,6 +63,41 @@ mod entity_loader;
 mod follow;
 mod input_manager;
 mod print_fps;
+mod restore_camera_shake;
+mod restore_on_respawn;
 mod scale_sprites;
+mod update_attachments;
+mod update_camera;
+mod update_camera_shake;
+mod update_camera_zoom;
 mod update_health;
+mod update_health_regen;
+mod update_invulnerable;
 mod update_lifecycle;
+mod update_status_effects;
+
+pub(crate) mod helpers {
+    use super::system_prelude::*;
+
+    /// Switches the entity's `Lifecycle` to `Death` and publishes
+    /// a `LifecycleEvent`, unless it is already dying or despawning.
+    /// Entities without a `Lifecycle` are deleted instead.
+    pub fn kill_entity(
+        entity: Entity,
+        lifecycle_opt: Option<&mut Lifecycle>,
+        entities: &Entities,
+        lifecycle_event_channel: &mut EventChannel<LifecycleEvent>,
+    ) {
+        if let Some(lifecycle) = lifecycle_opt {
+            if let Some(from) = lifecycle.die() {
+                lifecycle_event_channel.single_write(LifecycleEvent {
+                    entity,
+                    from,
+                    to: LifecycleState::Death,
+                });
+            }
+        } else {
+            entities.delete(entity).expect("Couldn't delete killed entity");
+        }
+    }
+}
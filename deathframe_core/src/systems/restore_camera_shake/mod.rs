@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+
+/// Removes the offsets, which the `UpdateCameraShakeSystem` applied
+/// to entities' `Transform` last frame, restoring the exact unshaken
+/// position and rotation.
+/// Should run before all other systems which read or move the shaken
+/// entity, like the `FollowSystem` and the `UpdateCameraSystem`,
+/// so they never see the shaken position.
+/// Positions set between frames, outside of systems, are overwritten
+/// with the unshaken position; stop the shake before moving it there.
+#[derive(Default)]
+pub struct RestoreCameraShakeSystem;
+
+impl<'a> System<'a> for RestoreCameraShakeSystem {
+    type SystemData = (
+        WriteStorage<'a, CameraShake>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(
+        &mut self,
+        (mut camera_shake_store, mut transform_store): Self::SystemData,
+    ) {
+        for (camera_shake, transform) in
+            (&mut camera_shake_store, &mut transform_store).join()
+        {
+            camera_shake.restore(transform);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Test that the `RestoreCameraShakeSystem` hides the shake
+//! of the `UpdateCameraShakeSystem` from other systems
+
+use super::*;
+use crate::systems::prelude::UpdateCameraShakeSystem;
+use amethyst::ecs::{Builder, RunNow, WorldExt};
+
+fn setup() -> (World, RestoreCameraShakeSystem, UpdateCameraShakeSystem) {
+    let mut world = World::new();
+    let mut restore_system = RestoreCameraShakeSystem::default();
+    let mut shake_system = UpdateCameraShakeSystem::default();
+    System::setup(&mut restore_system, &mut world);
+    System::setup(&mut shake_system, &mut world);
+    world.write_resource::<Time>().set_delta_seconds(0.1);
+    (world, restore_system, shake_system)
+}
+
+fn position_of(world: &World, entity: Entity) -> (f32, f32) {
+    let transform_store = world.read_storage::<Transform>();
+    let translation = transform_store.get(entity).unwrap().translation();
+    (translation.x, translation.y)
+}
+
+#[test]
+fn other_systems_only_see_unshaken_position() {
+    let (mut world, mut restore_system, mut shake_system) = setup();
+    let mut shake = CameraShake::new((4.0, 4.0));
+    shake.set_trauma(1.0);
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(10.0, 5.0, 0.0);
+    let entity = world.create_entity().with(transform).with(shake).build();
+
+    let mut did_shake = false;
+    for frame in 0 .. 5 {
+        restore_system.run_now(&world);
+        let expected = (10.0 + frame as f32, 5.0);
+        assert_eq!(position_of(&world, entity), expected);
+
+        // Moved by another system, like the `FollowSystem`.
+        world
+            .write_storage::<Transform>()
+            .get_mut(entity)
+            .unwrap()
+            .set_translation_x(expected.0 + 1.0);
+
+        shake_system.run_now(&world);
+        did_shake |= position_of(&world, entity) != (expected.0 + 1.0, 5.0);
+    }
+    assert!(did_shake);
+
+    restore_system.run_now(&world);
+    assert_eq!(position_of(&world, entity), (15.0, 5.0));
+}
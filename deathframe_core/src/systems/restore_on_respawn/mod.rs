@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// Restores the component `C` of `Respawnable` entities
+/// to its initial state, whenever they respawn.
+/// The component is snapshotted, when the system first sees the entity
+/// in the `Initial` or `Spawn` state, so components from prefabs
+/// are restored too. On respawn, the snapshot is re-inserted, even if
+/// the component was removed from the entity in the meantime.
+/// Run one of these systems for each component type, which should be
+/// restored. Reads the `LifecycleEvent`s published by the
+/// `UpdateLifecycleSystem`, so it should run after that system.
+pub struct RestoreOnRespawnSystem<C>
+where
+    C: 'static + Component + Clone + Send + Sync,
+{
+    snapshots: HashMap<Entity, C>,
+    reader_id: Option<ReaderId<LifecycleEvent>>,
+    _c:        PhantomData<C>,
+}
+
+impl<C> Default for RestoreOnRespawnSystem<C>
+where
+    C: 'static + Component + Clone + Send + Sync,
+{
+    fn default() -> Self {
+        Self {
+            snapshots: HashMap::new(),
+            reader_id: None,
+            _c:        Default::default(),
+        }
+    }
+}
+
+impl<'a, C> System<'a> for RestoreOnRespawnSystem<C>
+where
+    C: 'static + Component + Clone + Send + Sync,
+{
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, EventChannel<LifecycleEvent>>,
+        ReadStorage<'a, Lifecycle>,
+        ReadStorage<'a, Respawnable>,
+        WriteStorage<'a, C>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.reader_id = Some(
+            world
+                .fetch_mut::<EventChannel<LifecycleEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            lifecycle_event_channel,
+            lifecycle_store,
+            respawnable_store,
+            mut component_store,
+        ): Self::SystemData,
+    ) {
+        let reader_id = self
+            .reader_id
+            .as_mut()
+            .expect("RestoreOnRespawnSystem should have a ReaderId");
+
+        for event in lifecycle_event_channel.read(reader_id) {
+            let is_respawn = event.from == LifecycleState::Death
+                && event.to == LifecycleState::Spawn;
+            if is_respawn {
+                if let Some(snapshot) = self.snapshots.get(&event.entity) {
+                    component_store
+                        .insert(event.entity, snapshot.clone())
+                        .expect("Couldn't restore component on respawn");
+                }
+            }
+        }
+
+        self.snapshots.retain(|&entity, _| entities.is_alive(entity));
+
+        for (entity, lifecycle, _, component) in (
+            &entities,
+            &lifecycle_store,
+            &respawnable_store,
+            &component_store,
+        )
+            .join()
+        {
+            match &lifecycle.state {
+                LifecycleState::Initial | LifecycleState::Spawn => {
+                    self.snapshots
+                        .entry(entity)
+                        .or_insert_with(|| component.clone());
+                }
+                _ => (),
+            }
+        }
+    }
+}
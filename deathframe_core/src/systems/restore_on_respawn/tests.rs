@@ -0,0 +1,89 @@
+//! Test restoring of components by the `RestoreOnRespawnSystem`
+
+use super::*;
+use crate::systems::prelude::UpdateLifecycleSystem;
+use amethyst::ecs::{Builder, RunNow, WorldExt};
+
+type RestoreSizeSystem = RestoreOnRespawnSystem<Size>;
+
+fn setup() -> (World, UpdateLifecycleSystem, RestoreSizeSystem) {
+    let mut world = World::new();
+    let mut lifecycle_system = UpdateLifecycleSystem::default();
+    let mut restore_system = RestoreSizeSystem::default();
+    System::setup(&mut lifecycle_system, &mut world);
+    System::setup(&mut restore_system, &mut world);
+    (world, lifecycle_system, restore_system)
+}
+
+fn create_respawnable(world: &mut World) -> Entity {
+    world
+        .create_entity()
+        .with(Lifecycle::default())
+        .with(Respawnable::new())
+        .with(Health {
+            health:     5,
+            max_health: 5,
+        })
+        .with(Size::new(2.0, 2.0))
+        .build()
+}
+
+fn kill(world: &World, entity: Entity) {
+    world
+        .write_storage::<Health>()
+        .get_mut(entity)
+        .unwrap()
+        .kill();
+}
+
+fn size_of(world: &World, entity: Entity) -> Option<(f32, f32)> {
+    world
+        .read_storage::<Size>()
+        .get(entity)
+        .map(|size| (size.w, size.h))
+}
+
+#[test]
+fn restores_initial_component_on_respawn() {
+    let (mut world, mut lifecycle_system, mut restore_system) = setup();
+    let entity = create_respawnable(&mut world);
+    let mut run = |world: &World| {
+        lifecycle_system.run_now(world);
+        restore_system.run_now(world);
+    };
+
+    // Initial -> Spawn -> Alive
+    run(&world);
+    run(&world);
+    world.write_storage::<Size>().get_mut(entity).unwrap().w = 8.0;
+    assert_eq!(size_of(&world, entity), Some((8.0, 2.0)));
+
+    // Alive -> Death -> Spawn
+    kill(&world, entity);
+    run(&world);
+    run(&world);
+    assert_eq!(
+        world.read_storage::<Lifecycle>().get(entity).unwrap().state,
+        LifecycleState::Spawn
+    );
+    assert_eq!(size_of(&world, entity), Some((2.0, 2.0)));
+}
+
+#[test]
+fn reinserts_removed_component_on_respawn() {
+    let (mut world, mut lifecycle_system, mut restore_system) = setup();
+    let entity = create_respawnable(&mut world);
+    let mut run = |world: &World| {
+        lifecycle_system.run_now(world);
+        restore_system.run_now(world);
+    };
+
+    run(&world);
+    run(&world);
+    world.write_storage::<Size>().remove(entity);
+    kill(&world, entity);
+    run(&world);
+    assert_eq!(size_of(&world, entity), None);
+    run(&world);
+    assert_eq!(size_of(&world, entity), Some((2.0, 2.0)));
+}
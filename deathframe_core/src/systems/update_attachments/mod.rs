@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use amethyst::ecs::Component;
+
+/// Children can be attached to children, but only up to this depth.
+/// Also guards against attachment cycles.
+const MAX_DEPTH: usize = 32;
+
+/// Positions entities with `AttachedTo` at their parent entities,
+/// and syncs their `Unloaded`, `Hidden` and `HiddenPropagate` state
+/// with their parent's.
+/// Parents are always updated before their children.
+/// When a parent is deleted or switches to `LifecycleState::Despawn`,
+/// its children switch to `LifecycleState::Death` (publishing
+/// a `LifecycleEvent`), or are deleted, if they have no `Lifecycle`.
+/// Their `AttachedTo` is removed, so `Respawnable` children
+/// respawn detached, instead of dying again.
+/// Should run after all systems, which move the parent entities.
+#[derive(Default)]
+pub struct UpdateAttachmentsSystem;
+
+impl<'a> System<'a> for UpdateAttachmentsSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteStorage<'a, AttachedTo>,
+        WriteStorage<'a, Transform>,
+        ReadStorage<'a, Facing>,
+        WriteStorage<'a, Lifecycle>,
+        WriteStorage<'a, Unloaded>,
+        WriteStorage<'a, Hidden>,
+        WriteStorage<'a, HiddenPropagate>,
+        Write<'a, EventChannel<LifecycleEvent>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut attached_to_store,
+            mut transform_store,
+            facing_store,
+            mut lifecycle_store,
+            mut unloaded_store,
+            mut hidden_store,
+            mut hidden_propagate_store,
+            mut lifecycle_event_channel,
+        ): Self::SystemData,
+    ) {
+        let mut children = (&entities, &attached_to_store)
+            .join()
+            .map(|(child, attached_to)| {
+                (child, depth_of(attached_to, &attached_to_store))
+            })
+            .collect::<Vec<_>>();
+        children.sort_by_key(|(_, depth)| *depth);
+
+        for (child, _) in children {
+            let parent = match attached_to_store.get(child) {
+                Some(attached_to) => attached_to.parent,
+                None => continue,
+            };
+
+            let is_parent_despawned = !entities.is_alive(parent)
+                || lifecycle_store
+                    .get(parent)
+                    .map(|lifecycle| {
+                        lifecycle.state == LifecycleState::Despawn
+                    })
+                    .unwrap_or(false);
+            if is_parent_despawned {
+                kill_entity(
+                    child,
+                    lifecycle_store.get_mut(child),
+                    &entities,
+                    &mut lifecycle_event_channel,
+                );
+                attached_to_store.remove(child);
+                continue;
+            }
+
+            sync_flag(&mut unloaded_store, parent, child);
+            sync_flag(&mut hidden_store, parent, child);
+            sync_flag(&mut hidden_propagate_store, parent, child);
+
+            let parent_transform = match transform_store.get(parent) {
+                Some(transform) => transform.clone(),
+                None => continue,
+            };
+            let parent_flip = if let Some(facing) = facing_store.get(parent) {
+                (facing.is_flipped(&Axis::X), facing.is_flipped(&Axis::Y))
+            } else {
+                let scale = parent_transform.scale();
+                (scale.x < 0.0, scale.y < 0.0)
+            };
+
+            if let (Some(attached_to), Some(child_transform)) = (
+                attached_to_store.get_mut(child),
+                transform_store.get_mut(child),
+            ) {
+                attached_to.update(
+                    &parent_transform,
+                    parent_flip,
+                    child_transform,
+                );
+            }
+        }
+    }
+}
+
+/// Returns how many ancestors the entity with the given `AttachedTo` has.
+fn depth_of(
+    attached_to: &AttachedTo,
+    attached_to_store: &WriteStorage<AttachedTo>,
+) -> usize {
+    let mut depth = 0;
+    let mut parent = attached_to.parent;
+    while let Some(parent_attached_to) = attached_to_store.get(parent) {
+        depth += 1;
+        if depth >= MAX_DEPTH {
+            break;
+        }
+        parent = parent_attached_to.parent;
+    }
+    depth
+}
+
+/// Inserts or removes the flag component on the child,
+/// so it matches the parent.
+fn sync_flag<T>(store: &mut WriteStorage<T>, parent: Entity, child: Entity)
+where
+    T: Component + Default,
+{
+    let parent_has = store.contains(parent);
+    if parent_has != store.contains(child) {
+        if parent_has {
+            store
+                .insert(child, T::default())
+                .expect("Couldn't sync component with parent entity");
+        } else {
+            store.remove(child);
+        }
+    }
+}
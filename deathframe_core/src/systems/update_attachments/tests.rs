@@ -0,0 +1,94 @@
+//! Test handling of despawned parents by the `UpdateAttachmentsSystem`
+
+use super::*;
+use amethyst::ecs::{Builder, RunNow, WorldExt};
+
+fn setup() -> (World, UpdateAttachmentsSystem, ReaderId<LifecycleEvent>) {
+    let mut world = World::new();
+    let mut system = UpdateAttachmentsSystem::default();
+    System::setup(&mut system, &mut world);
+    let reader_id = world
+        .write_resource::<EventChannel<LifecycleEvent>>()
+        .register_reader();
+    (world, system, reader_id)
+}
+
+fn create_parent(world: &mut World) -> Entity {
+    world.create_entity().with(Transform::default()).build()
+}
+
+fn create_child(world: &mut World, parent: Entity) -> Entity {
+    world
+        .create_entity()
+        .with(Transform::default())
+        .with(AttachedTo::new(parent))
+        .build()
+}
+
+fn set_state(world: &World, entity: Entity, state: LifecycleState) {
+    world
+        .write_storage::<Lifecycle>()
+        .get_mut(entity)
+        .unwrap()
+        .state = state;
+}
+
+fn state_of(world: &World, entity: Entity) -> LifecycleState {
+    world
+        .read_storage::<Lifecycle>()
+        .get(entity)
+        .unwrap()
+        .state
+        .clone()
+}
+
+fn count_events(
+    world: &World,
+    reader_id: &mut ReaderId<LifecycleEvent>,
+) -> usize {
+    world
+        .read_resource::<EventChannel<LifecycleEvent>>()
+        .read(reader_id)
+        .count()
+}
+
+#[test]
+fn deletes_children_of_deleted_parent() {
+    let (mut world, mut system, _) = setup();
+    let parent = create_parent(&mut world);
+    let child = create_child(&mut world, parent);
+
+    world.delete_entity(parent).unwrap();
+    system.run_now(&world);
+    world.maintain();
+    assert!(!world.is_alive(child));
+}
+
+#[test]
+fn kills_and_detaches_children_of_despawned_parent() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let parent = create_parent(&mut world);
+    let child = create_child(&mut world, parent);
+    world
+        .write_storage::<Lifecycle>()
+        .insert(parent, Lifecycle::default())
+        .unwrap();
+    world
+        .write_storage::<Lifecycle>()
+        .insert(child, Lifecycle::default())
+        .unwrap();
+    set_state(&world, child, LifecycleState::Alive);
+
+    set_state(&world, parent, LifecycleState::Despawn);
+    system.run_now(&world);
+    assert_eq!(state_of(&world, child), LifecycleState::Death);
+    assert!(!world.read_storage::<AttachedTo>().contains(child));
+    assert_eq!(count_events(&world, &mut reader_id), 1);
+
+    // Respawned, like a `Respawnable` child would.
+    set_state(&world, child, LifecycleState::Alive);
+    world.delete_entity(parent).unwrap();
+    system.run_now(&world);
+    assert_eq!(state_of(&world, child), LifecycleState::Alive);
+    assert_eq!(count_events(&world, &mut reader_id), 0);
+}
@@ -0,0 +1,101 @@
+use super::system_prelude::*;
+
+/// Moves entities with a `Camera` and a `Transform` towards their
+/// camera's target, driven by game `Time`.
+/// Confines the visible area of the camera to the camera's bounds,
+/// using the entity's `CameraZoom` (without its letterbox),
+/// its amethyst `Camera` projection, or its `Size`.
+/// Should run after all systems, which move the camera's target,
+/// and after the `RestoreCameraShakeSystem`, if the camera shakes.
+#[derive(Default)]
+pub struct UpdateCameraSystem;
+
+impl<'a> System<'a> for UpdateCameraSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        WriteStorage<'a, Camera>,
+        WriteStorage<'a, Transform>,
+        ReadStorage<'a, CameraZoom>,
+        ReadStorage<'a, AmethystCamera>,
+        ReadStorage<'a, Size>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut camera_store,
+            mut transform_store,
+            camera_zoom_store,
+            amethyst_camera_store,
+            size_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+
+        for (entity, camera) in (&entities, &mut camera_store).join() {
+            let target_pos_opt = camera
+                .target
+                .and_then(|target| transform_store.get(target))
+                .map(|transform| {
+                    let translation = transform.translation();
+                    (translation.x, translation.y)
+                });
+
+            if let Some(transform) = transform_store.get_mut(entity) {
+                let viewport = viewport_rect(
+                    transform,
+                    camera_zoom_store.get(entity),
+                    amethyst_camera_store.get(entity),
+                    size_store.get(entity),
+                );
+                let current_pos = {
+                    let translation = transform.translation();
+                    (translation.x, translation.y)
+                };
+                let pos =
+                    camera.update(dt, current_pos, target_pos_opt, &viewport);
+                transform.set_translation_x(pos.0);
+                transform.set_translation_y(pos.1);
+            }
+        }
+    }
+}
+
+/// Returns the visible area, relative to the camera's position.
+/// Uses the last `CameraView` of the `CameraZoom`, or the orthographic
+/// projection of the amethyst `Camera`, scaled by the `Transform`'s scale.
+/// Falls back to the `Size`.
+fn viewport_rect(
+    transform: &Transform,
+    camera_zoom_opt: Option<&CameraZoom>,
+    amethyst_camera_opt: Option<&AmethystCamera>,
+    size_opt: Option<&Size>,
+) -> Rect {
+    let scale = transform.scale();
+    if let Some(view) = camera_zoom_opt.and_then(CameraZoom::view) {
+        let visible = Size::new(
+            view.visible.w * scale.x.abs(),
+            view.visible.h * scale.y.abs(),
+        );
+        return Rect::from(&visible);
+    }
+
+    let ortho_opt = amethyst_camera_opt.and_then(|amethyst_camera| {
+        amethyst_camera.projection().as_orthographic()
+    });
+    if let Some(ortho) = ortho_opt {
+        let (left, right) = (ortho.left() * scale.x, ortho.right() * scale.x);
+        let (bottom, top) = (ortho.bottom() * scale.y, ortho.top() * scale.y);
+        Rect {
+            top:    top.max(bottom),
+            bottom: top.min(bottom),
+            left:   left.min(right),
+            right:  left.max(right),
+        }
+    } else {
+        size_opt.map(Rect::from).unwrap_or_default()
+    }
+}
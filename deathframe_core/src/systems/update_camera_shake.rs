@@ -0,0 +1,57 @@
+use super::system_prelude::*;
+
+/// Applies the offsets of entities' `CameraShake` to their `Transform`,
+/// driven by game `Time`.
+/// The offsets are removed again by the `RestoreCameraShakeSystem`,
+/// which should run at the start of the next frame.
+/// If the entity has a `Camera` with bounds, the shaken position
+/// is confined to the bounds as well, and if it has a pixel-perfect
+/// `CameraZoom`, it is snapped to whole screen pixels.
+/// Should run after all systems which move the shaken entity,
+/// including the `UpdateCameraSystem` and the `UpdateCameraZoomSystem`.
+#[derive(Default)]
+pub struct UpdateCameraShakeSystem;
+
+impl<'a> System<'a> for UpdateCameraShakeSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        WriteStorage<'a, CameraShake>,
+        WriteStorage<'a, Transform>,
+        ReadStorage<'a, Camera>,
+        ReadStorage<'a, CameraZoom>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut camera_shake_store,
+            mut transform_store,
+            camera_store,
+            camera_zoom_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+
+        for (_, camera_shake, transform, camera_opt, camera_zoom_opt) in (
+            &entities,
+            &mut camera_shake_store,
+            &mut transform_store,
+            camera_store.maybe(),
+            camera_zoom_store.maybe(),
+        )
+            .join()
+        {
+            if let Some(offsets) = camera_shake.update(dt) {
+                camera_shake.apply(
+                    transform,
+                    offsets,
+                    camera_opt,
+                    camera_zoom_opt,
+                );
+            }
+        }
+    }
+}
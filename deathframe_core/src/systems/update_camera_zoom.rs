@@ -0,0 +1,63 @@
+use super::system_prelude::*;
+use amethyst::window::ScreenDimensions;
+
+/// Fits the amethyst `Camera` projection of entities with a `CameraZoom`
+/// into the window, and transitions their zoom, driven by game `Time`.
+/// In pixel-perfect mode, also snaps the `Transform`'s position
+/// to whole screen pixels.
+/// Should run after the `UpdateCameraSystem`,
+/// and before the `UpdateCameraShakeSystem`.
+#[derive(Default)]
+pub struct UpdateCameraZoomSystem;
+
+impl<'a> System<'a> for UpdateCameraZoomSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        ReadExpect<'a, ScreenDimensions>,
+        WriteStorage<'a, CameraZoom>,
+        WriteStorage<'a, AmethystCamera>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            screen_dimensions,
+            mut camera_zoom_store,
+            mut amethyst_camera_store,
+            mut transform_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+        let window = (screen_dimensions.width(), screen_dimensions.height());
+
+        for (_, camera_zoom, amethyst_camera, transform_opt) in (
+            &entities,
+            &mut camera_zoom_store,
+            &mut amethyst_camera_store,
+            (&mut transform_store).maybe(),
+        )
+            .join()
+        {
+            let view = camera_zoom.update(dt, window);
+            if let Some(ortho) =
+                amethyst_camera.projection_mut().as_orthographic_mut()
+            {
+                let half_size = view.size.half();
+                ortho.set_left_and_right(-half_size.w, half_size.w);
+                ortho.set_bottom_and_top(-half_size.h, half_size.h);
+            }
+
+            if let Some(transform) = transform_opt {
+                let translation = transform.translation();
+                let snapped =
+                    camera_zoom.snap((translation.x, translation.y));
+                transform.set_translation_x(snapped.0);
+                transform.set_translation_y(snapped.1);
+            }
+        }
+    }
+}
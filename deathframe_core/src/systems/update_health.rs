@@ -1,6 +1,9 @@
 use super::system_prelude::*;
 
 /// Updates entities' `Health`, via `HealthAction`s from their `HealthActionQueue`.
+/// Damage actions (`Lose` and `LosePercent`) are ignored
+/// for active `Invulnerable` entities, except for their exempt action.
+/// Publishes a `HealthChanged` event for every action that changed health.
 #[derive(Default)]
 pub struct UpdateHealthSystem;
 
@@ -9,6 +12,8 @@ impl<'a> System<'a> for UpdateHealthSystem {
         Entities<'a>,
         WriteStorage<'a, Health>,
         WriteStorage<'a, HealthActionQueue>,
+        WriteStorage<'a, Invulnerable>,
+        Write<'a, EventChannel<HealthChanged>>,
     );
 
     fn run(
@@ -17,20 +22,36 @@ impl<'a> System<'a> for UpdateHealthSystem {
             entities,
             mut health_store,
             mut health_action_queue_store,
+            mut invulnerable_store,
+            mut health_changed_channel,
         ): Self::SystemData,
     ) {
-        for (_entity, health, health_action_queue) in
-            (&entities, &mut health_store, &mut health_action_queue_store)
-                .join()
+        for (entity, health, health_action_queue, mut invulnerable_opt) in (
+            &entities,
+            &mut health_store,
+            &mut health_action_queue_store,
+            (&mut invulnerable_store).maybe(),
+        )
+            .join()
         {
             for action in health_action_queue.drain_actions() {
-                match action {
-                    HealthAction::Gain(hp) => {
-                        health.gain(hp);
-                    }
-                    HealthAction::Lose(hp) => {
-                        health.lose(hp);
-                    }
+                if invulnerable_opt
+                    .as_mut()
+                    .map(|invulnerable| invulnerable.ignores_action(&action))
+                    .unwrap_or(false)
+                {
+                    continue;
+                }
+
+                let old = health.clone();
+                health.apply(&action);
+                if *health != old {
+                    health_changed_channel.single_write(HealthChanged {
+                        entity,
+                        old,
+                        new: health.clone(),
+                        source: action,
+                    });
                 }
             }
         }
@@ -0,0 +1,54 @@
+use super::system_prelude::*;
+
+/// Regenerates health of entities with `HealthRegen`,
+/// by queueing `HealthAction::Gain` actions in their `HealthActionQueue`.
+/// Driven by game `Time`, and skips entities whose
+/// `Lifecycle` (if they have one) is not `Alive`.
+#[derive(Default)]
+pub struct UpdateHealthRegenSystem;
+
+impl<'a> System<'a> for UpdateHealthRegenSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        ReadStorage<'a, Health>,
+        WriteStorage<'a, HealthRegen>,
+        WriteStorage<'a, HealthActionQueue>,
+        ReadStorage<'a, Lifecycle>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            health_store,
+            mut health_regen_store,
+            mut health_action_queue_store,
+            lifecycle_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+
+        for (_, health, health_regen, health_action_queue, lifecycle_opt) in (
+            &entities,
+            &health_store,
+            &mut health_regen_store,
+            &mut health_action_queue_store,
+            lifecycle_store.maybe(),
+        )
+            .join()
+        {
+            let is_alive = lifecycle_opt
+                .map(|lifecycle| lifecycle.state == LifecycleState::Alive)
+                .unwrap_or(true);
+            if !is_alive {
+                continue;
+            }
+            let hp = health_regen.update(dt, health);
+            if hp > 0 {
+                health_action_queue.gain(hp);
+            }
+        }
+    }
+}
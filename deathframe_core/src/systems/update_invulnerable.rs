@@ -0,0 +1,65 @@
+use super::system_prelude::*;
+
+/// Counts down entities' `Invulnerable` durations (in game time),
+/// and removes the component once the invulnerability runs out.
+/// Also handles the optional blink effect, by toggling `HiddenPropagate`.
+/// `Hidden` is left to other systems, so the blink never shows an entity,
+/// which the `EntityLoaderSystem` (or anything else) hid.
+#[derive(Default)]
+pub struct UpdateInvulnerableSystem;
+
+impl<'a> System<'a> for UpdateInvulnerableSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        WriteStorage<'a, Invulnerable>,
+        WriteStorage<'a, HiddenPropagate>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut invulnerable_store,
+            mut hidden_propagate_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+        let mut finished = Vec::new();
+
+        for (entity, invulnerable) in
+            (&entities, &mut invulnerable_store).join()
+        {
+            invulnerable.update(dt);
+
+            // Only toggle `HiddenPropagate`,
+            // if it wasn't added by something else.
+            let should_hide = invulnerable.should_hide();
+            if should_hide != invulnerable.is_hiding
+                && (invulnerable.is_hiding
+                    || !hidden_propagate_store.contains(entity))
+            {
+                if should_hide {
+                    hidden_propagate_store
+                        .insert(entity, HiddenPropagate::new())
+                        .expect(
+                            "Couldn't insert HiddenPropagate for Invulnerable \
+                             entity",
+                        );
+                } else {
+                    hidden_propagate_store.remove(entity);
+                }
+                invulnerable.is_hiding = should_hide;
+            }
+
+            if !invulnerable.is_active() {
+                finished.push(entity);
+            }
+        }
+
+        for entity in finished {
+            invulnerable_store.remove(entity);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+
+/// Handles updating of entity's `Lifecycle` component's `LifecycleState`.
+/// Will also delete entities when their state switches to `Despawn`.
+/// `Alive` entities with an expired `Lifetime` switch to `Death`.
+/// `Respawnable` entities switch from `Death` back to `Spawn`,
+/// instead of despawning; see `Respawnable` for details.
+/// Respawnable entities, which use checkpoints, are moved to the
+/// active checkpoint of the `Checkpoints` resource when they (re)spawn.
+/// Publishes a `LifecycleEvent` for every state transition,
+/// and a `HealthChanged` event, when a respawn resets an entity's `Health`.
+#[derive(Default)]
+pub struct UpdateLifecycleSystem;
+
+impl<'a> System<'a> for UpdateLifecycleSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        Read<'a, Checkpoints>,
+        WriteStorage<'a, Lifecycle>,
+        WriteStorage<'a, Health>,
+        WriteStorage<'a, Lifetime>,
+        WriteStorage<'a, Respawnable>,
+        WriteStorage<'a, Transform>,
+        Write<'a, EventChannel<LifecycleEvent>>,
+        Write<'a, EventChannel<HealthChanged>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            checkpoints,
+            mut lifecycle_store,
+            mut health_store,
+            mut lifetime_store,
+            mut respawnable_store,
+            mut transform_store,
+            mut lifecycle_event_channel,
+            mut health_changed_channel,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_time();
+
+        for (
+            entity,
+            lifecycle,
+            mut health_opt,
+            mut lifetime_opt,
+            mut respawnable_opt,
+            mut transform_opt,
+        ) in (
+            &entities,
+            &mut lifecycle_store,
+            (&mut health_store).maybe(),
+            (&mut lifetime_store).maybe(),
+            (&mut respawnable_store).maybe(),
+            (&mut transform_store).maybe(),
+        )
+            .join()
+        {
+            let from = lifecycle.state.clone();
+
+            let is_expired = if let Some(lifetime) = lifetime_opt.as_mut() {
+                match &lifecycle.state {
+                    LifecycleState::Spawn | LifecycleState::Alive => {
+                        lifetime.update(dt.as_secs_f32());
+                    }
+                    _ => (),
+                }
+                lifetime.is_expired()
+            } else {
+                false
+            };
+
+            if !lifecycle.is_prolonged() {
+                match &lifecycle.state {
+                    LifecycleState::Initial => {
+                        if let (Some(respawnable), Some(transform)) =
+                            (respawnable_opt.as_mut(), transform_opt.as_mut())
+                        {
+                            // Remember the initial position as the spawn point,
+                            // if the respawnable entity has no spawn point.
+                            if respawnable.spawn_point.is_none() {
+                                let trans = transform.translation();
+                                respawnable.set_spawn_point((trans.x, trans.y));
+                            }
+                            // Spawn at the active checkpoint.
+                            let checkpoint_opt = checkpoint_spawn_point(
+                                respawnable,
+                                &checkpoints,
+                            );
+                            if let Some((x, y)) = checkpoint_opt {
+                                transform.set_translation_x(x);
+                                transform.set_translation_y(y);
+                            }
+                        }
+                        lifecycle.next_state().unwrap();
+                    }
+                    LifecycleState::Spawn => {
+                        lifecycle.next_state().unwrap();
+                    }
+                    LifecycleState::Alive => {
+                        let is_dead = health_opt
+                            .as_ref()
+                            .map(|health| !health.is_alive())
+                            .unwrap_or(false);
+                        if is_dead || is_expired {
+                            lifecycle.next_state().unwrap();
+                        }
+                    }
+                    LifecycleState::Death => {
+                        if let Some(respawnable) = respawnable_opt.as_ref() {
+                            lifecycle.respawn();
+                            let spawn_point_opt = checkpoint_spawn_point(
+                                respawnable,
+                                &checkpoints,
+                            )
+                            .or(respawnable.spawn_point());
+                            if let (Some((x, y)), Some(transform)) =
+                                (spawn_point_opt, transform_opt.as_mut())
+                            {
+                                transform.set_translation_x(x);
+                                transform.set_translation_y(y);
+                            }
+                            if let Some(health) = health_opt.as_mut() {
+                                let action =
+                                    HealthAction::Set(health.max_health);
+                                let old = health.clone();
+                                health.apply(&action);
+                                if **health != old {
+                                    health_changed_channel.single_write(
+                                        HealthChanged {
+                                            entity,
+                                            old,
+                                            new: health.clone(),
+                                            source: action,
+                                        },
+                                    );
+                                }
+                            }
+                            if let Some(lifetime) = lifetime_opt.as_mut() {
+                                lifetime.reset();
+                            }
+                        } else {
+                            lifecycle.next_state().unwrap();
+                        }
+                    }
+                    LifecycleState::Despawn => {
+                        entities.delete(entity).expect(
+                            "Couldn't delete entity with \
+                             LifecycleState::Despawn",
+                        );
+                    }
+                }
+            }
+
+            if lifecycle.state != from {
+                lifecycle_event_channel.single_write(LifecycleEvent {
+                    entity,
+                    from,
+                    to: lifecycle.state.clone(),
+                });
+            }
+
+            lifecycle.update(dt);
+        }
+    }
+}
+
+/// Returns the active checkpoint's spawn point,
+/// if the respawnable entity uses checkpoints.
+fn checkpoint_spawn_point(
+    respawnable: &Respawnable,
+    checkpoints: &Checkpoints,
+) -> Option<(f32, f32)> {
+    if respawnable.uses_checkpoints() {
+        checkpoints.spawn_point()
+    } else {
+        None
+    }
+}
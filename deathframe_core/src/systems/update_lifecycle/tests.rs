@@ -0,0 +1,191 @@
+//! Test state transitions and respawning of the `UpdateLifecycleSystem`
+
+use super::*;
+use amethyst::ecs::{Builder, RunNow, WorldExt};
+
+fn setup() -> (World, UpdateLifecycleSystem, ReaderId<LifecycleEvent>) {
+    let mut world = World::new();
+    let mut system = UpdateLifecycleSystem::default();
+    System::setup(&mut system, &mut world);
+    let reader_id = world
+        .write_resource::<EventChannel<LifecycleEvent>>()
+        .register_reader();
+    (world, system, reader_id)
+}
+
+fn create_at(world: &mut World, (x, y): (f32, f32)) -> Entity {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(x, y, 0.0);
+    world
+        .create_entity()
+        .with(transform)
+        .with(Lifecycle::default())
+        .with(Health {
+            health:     5,
+            max_health: 5,
+        })
+        .build()
+}
+
+fn make_respawnable(world: &World, entity: Entity, respawnable: Respawnable) {
+    world
+        .write_storage::<Respawnable>()
+        .insert(entity, respawnable)
+        .unwrap();
+}
+
+fn kill_and_move_to(world: &World, entity: Entity, (x, y): (f32, f32)) {
+    world
+        .write_storage::<Health>()
+        .get_mut(entity)
+        .unwrap()
+        .kill();
+    let mut transform_store = world.write_storage::<Transform>();
+    let transform = transform_store.get_mut(entity).unwrap();
+    transform.set_translation_x(x);
+    transform.set_translation_y(y);
+}
+
+fn state_of(world: &World, entity: Entity) -> LifecycleState {
+    world
+        .read_storage::<Lifecycle>()
+        .get(entity)
+        .unwrap()
+        .state
+        .clone()
+}
+
+fn position_of(world: &World, entity: Entity) -> (f32, f32) {
+    let transform_store = world.read_storage::<Transform>();
+    let translation = transform_store.get(entity).unwrap().translation();
+    (translation.x, translation.y)
+}
+
+fn read_transitions(
+    world: &World,
+    reader_id: &mut ReaderId<LifecycleEvent>,
+) -> Vec<(LifecycleState, LifecycleState)> {
+    world
+        .read_resource::<EventChannel<LifecycleEvent>>()
+        .read(reader_id)
+        .map(|event| (event.from.clone(), event.to.clone()))
+        .collect()
+}
+
+#[test]
+fn despawns_dead_entities() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let entity = create_at(&mut world, (0.0, 0.0));
+
+    system.run_now(&world);
+    system.run_now(&world);
+    kill_and_move_to(&world, entity, (0.0, 0.0));
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(state_of(&world, entity), LifecycleState::Despawn);
+    system.run_now(&world);
+    world.maintain();
+    assert!(!world.is_alive(entity));
+    assert_eq!(read_transitions(&world, &mut reader_id), vec![
+        (LifecycleState::Initial, LifecycleState::Spawn),
+        (LifecycleState::Spawn, LifecycleState::Alive),
+        (LifecycleState::Alive, LifecycleState::Death),
+        (LifecycleState::Death, LifecycleState::Despawn),
+    ]);
+}
+
+#[test]
+fn respawns_at_initial_position_with_full_health() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let entity = create_at(&mut world, (1.0, 2.0));
+    make_respawnable(&world, entity, Respawnable::new());
+
+    system.run_now(&world);
+    system.run_now(&world);
+    kill_and_move_to(&world, entity, (10.0, 20.0));
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(state_of(&world, entity), LifecycleState::Spawn);
+    assert_eq!(position_of(&world, entity), (1.0, 2.0));
+    assert!(world
+        .read_storage::<Health>()
+        .get(entity)
+        .unwrap()
+        .has_full_health());
+    assert_eq!(read_transitions(&world, &mut reader_id), vec![
+        (LifecycleState::Initial, LifecycleState::Spawn),
+        (LifecycleState::Spawn, LifecycleState::Alive),
+        (LifecycleState::Alive, LifecycleState::Death),
+        (LifecycleState::Death, LifecycleState::Spawn),
+    ]);
+}
+
+#[test]
+fn respawns_at_spawn_point() {
+    let (mut world, mut system, _) = setup();
+    let entity = create_at(&mut world, (1.0, 2.0));
+    make_respawnable(
+        &world,
+        entity,
+        Respawnable::new().with_spawn_point((5.0, 6.0)),
+    );
+
+    system.run_now(&world);
+    system.run_now(&world);
+    kill_and_move_to(&world, entity, (10.0, 20.0));
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(position_of(&world, entity), (5.0, 6.0));
+}
+
+fn activate_checkpoint(world: &World, spawn_point: (f32, f32)) {
+    world.write_resource::<Checkpoints>().activate(CheckpointData {
+        id: String::from("checkpoint"),
+        spawn_point,
+    });
+}
+
+#[test]
+fn respawns_at_active_checkpoint() {
+    let (mut world, mut system, _) = setup();
+    let entity = create_at(&mut world, (1.0, 2.0));
+    make_respawnable(&world, entity, Respawnable::new().with_checkpoints(true));
+
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(position_of(&world, entity), (1.0, 2.0));
+
+    activate_checkpoint(&world, (7.0, 8.0));
+    kill_and_move_to(&world, entity, (10.0, 20.0));
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(state_of(&world, entity), LifecycleState::Spawn);
+    assert_eq!(position_of(&world, entity), (7.0, 8.0));
+}
+
+#[test]
+fn spawns_at_checkpoint_which_was_active_before_loading() {
+    let (mut world, mut system, _) = setup();
+    activate_checkpoint(&world, (7.0, 8.0));
+    let entity = create_at(&mut world, (1.0, 2.0));
+    make_respawnable(&world, entity, Respawnable::new().with_checkpoints(true));
+
+    system.run_now(&world);
+    assert_eq!(position_of(&world, entity), (7.0, 8.0));
+}
+
+#[test]
+fn ignores_checkpoints_unless_used() {
+    let (mut world, mut system, _) = setup();
+    activate_checkpoint(&world, (7.0, 8.0));
+    let entity = create_at(&mut world, (1.0, 2.0));
+    make_respawnable(&world, entity, Respawnable::new());
+
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(position_of(&world, entity), (1.0, 2.0));
+    kill_and_move_to(&world, entity, (10.0, 20.0));
+    system.run_now(&world);
+    system.run_now(&world);
+    assert_eq!(position_of(&world, entity), (1.0, 2.0));
+}
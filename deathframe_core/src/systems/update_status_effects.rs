@@ -0,0 +1,51 @@
+use super::system_prelude::*;
+
+/// Deals damage of entities' `StatusEffects`, by queueing
+/// `HealthAction::Lose` actions in their `HealthActionQueue`.
+/// Driven by game `Time`, and skips entities whose
+/// `Lifecycle` (if they have one) is not `Alive`.
+#[derive(Default)]
+pub struct UpdateStatusEffectsSystem;
+
+impl<'a> System<'a> for UpdateStatusEffectsSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        WriteStorage<'a, StatusEffects>,
+        WriteStorage<'a, HealthActionQueue>,
+        ReadStorage<'a, Lifecycle>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut status_effects_store,
+            mut health_action_queue_store,
+            lifecycle_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+
+        for (_, status_effects, health_action_queue, lifecycle_opt) in (
+            &entities,
+            &mut status_effects_store,
+            &mut health_action_queue_store,
+            lifecycle_store.maybe(),
+        )
+            .join()
+        {
+            let is_alive = lifecycle_opt
+                .map(|lifecycle| lifecycle.state == LifecycleState::Alive)
+                .unwrap_or(true);
+            if !is_alive {
+                continue;
+            }
+            let damage = status_effects.update(dt);
+            if damage > 0 {
+                health_action_queue.lose(damage);
+            }
+        }
+    }
+}
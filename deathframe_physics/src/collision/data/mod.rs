@@ -23,6 +23,7 @@ where
     pub state:                       CollisionState,
     pub tag:                         C,
     pub id:                          Index,
+    pub(crate) is_tile_map:          bool,
     pub(crate) did_update_collision: bool,
 }
 
@@ -30,6 +31,12 @@ impl<C> CollisionData<C>
 where
     C: CollisionTag,
 {
+    /// Returns `true` if this is a collision with
+    /// the solid cells of a `TileCollisionMap`.
+    pub fn is_tile_map(&self) -> bool {
+        self.is_tile_map
+    }
+
     pub(crate) fn should_remove(&self) -> bool {
         if let CollisionState::Leave = &self.state {
             !self.did_update_collision
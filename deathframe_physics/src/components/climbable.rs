@@ -0,0 +1,34 @@
+use super::component_prelude::*;
+
+/// A `Climbable` is an area (ladder, vine, ...), relative to this
+/// entity's `Transform`, which `Climber` entities can climb on.
+/// See the `HandleClimbingSystem` for details.
+#[derive(Component, Builder, Clone, Debug, Deserialize)]
+#[storage(VecStorage)]
+#[builder(pattern = "owned")]
+#[serde(deny_unknown_fields)]
+pub struct Climbable {
+    /// The climbable area, relative to this entity's position.
+    pub(crate) rect:        Rect,
+    /// If `true`, the top of this climbable area acts like
+    /// a one-way platform, so `Climber`s can stand on top of it.
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) one_way_top: bool,
+}
+
+impl Climbable {
+    /// Returns a new `ClimbableBuilder`.
+    pub fn builder() -> ClimbableBuilder {
+        ClimbableBuilder::default()
+    }
+}
+
+impl From<Rect> for Climbable {
+    fn from(rect: Rect) -> Self {
+        Self {
+            rect,
+            one_way_top: false,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+use crate::collision::collision_check;
+
+/// How far a `Climber`'s bottom may be below the top of a
+/// _one-way-top_ `Climbable`, to still count as standing on top of it.
+const TOP_MARGIN: f32 = 0.5;
+
+/// What the `HandleClimbingSystem` should do with a `Climber` entity
+/// this frame. Returned by `Climber::update`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ClimbUpdate {
+    /// Snap to the climbable area's horizontal center `x`,
+    /// and move vertically with the velocity `y`.
+    Climb { x: f32, y: f32 },
+    /// Land on top of a one-way-top climbable,
+    /// by setting the vertical velocity to `y`.
+    Land { y: f32 },
+    /// Not climbing; don't touch the entity.
+    None,
+}
+
+/// Entities with `Climber` can climb on `Climbable` areas.
+/// Set the climb _intent_ from your game's input handling,
+/// the `HandleClimbingSystem` does the rest.
+#[derive(Component, Builder, Clone, Debug, Deserialize)]
+#[storage(VecStorage)]
+#[builder(pattern = "owned")]
+#[serde(deny_unknown_fields)]
+pub struct Climber {
+    /// The vertical speed with which this entity climbs.
+    pub(crate) speed:       f32,
+    #[builder(setter(skip))]
+    #[serde(skip)]
+    pub(crate) intent:      f32,
+    #[builder(setter(skip))]
+    #[serde(skip)]
+    pub(crate) is_climbing: bool,
+    #[builder(setter(skip))]
+    #[serde(skip)]
+    pub(crate) is_on_top:   bool,
+}
+
+impl Climber {
+    /// Create a new `Climber` with the given climbing speed.
+    pub fn new(speed: f32) -> Self {
+        Self {
+            speed,
+            intent: 0.0,
+            is_climbing: false,
+            is_on_top: false,
+        }
+    }
+
+    /// Returns a new `ClimberBuilder`.
+    pub fn builder() -> ClimberBuilder {
+        ClimberBuilder::default()
+    }
+
+    /// Set the vertical climb intent, usually from an input axis.
+    /// `1.0` climbs up, `-1.0` climbs down, `0.0` doesn't climb.
+    /// The value is clamped between `-1.0` and `1.0`.
+    /// Holding a non-zero intent inside a `Climbable` area starts climbing.
+    pub fn set_intent(&mut self, intent: f32) {
+        self.intent = intent.min(1.0).max(-1.0);
+    }
+
+    /// Stop climbing, for example when jumping off a ladder.
+    /// Climbing starts again, once a non-zero intent is set.
+    pub fn stop_climbing(&mut self) {
+        self.is_climbing = false;
+        self.intent = 0.0;
+    }
+
+    /// Returns `true` if this entity is currently climbing.
+    pub fn is_climbing(&self) -> bool {
+        self.is_climbing
+    }
+
+    /// Returns `true` if this entity is standing on top of
+    /// a _one-way-top_ `Climbable`.
+    pub fn is_on_top(&self) -> bool {
+        self.is_on_top
+    }
+
+    /// Updates the climbing state for this frame, and returns
+    /// what should happen to the climber entity.
+    /// `rect` is the climber's absolute bounding rect, `velocity_y`
+    /// its current vertical velocity, and `areas` are the absolute
+    /// `Climbable` areas, with their _one-way-top_ flags.
+    pub(crate) fn update<'r, I>(
+        &mut self,
+        dt: f32,
+        rect: &Rect,
+        velocity_y: f32,
+        areas: I,
+    ) -> ClimbUpdate
+    where
+        I: IntoIterator<Item = (&'r Rect, bool)>,
+    {
+        let mut overlapping_area_opt = None;
+        let mut top_area_opt = None;
+        for (area, one_way_top) in areas {
+            if overlapping_area_opt.is_none()
+                && collision_check::do_rects_intersect(rect, area)
+            {
+                overlapping_area_opt = Some(area);
+            }
+            if top_area_opt.is_none()
+                && one_way_top
+                && rect.left < area.right
+                && rect.right > area.left
+                && rect.bottom >= area.top - TOP_MARGIN
+            {
+                top_area_opt = Some(area);
+            }
+        }
+
+        self.is_on_top = false;
+
+        let climbing_area_opt = if self.is_climbing {
+            overlapping_area_opt
+        } else if self.intent != 0.0 {
+            let intent = self.intent;
+            top_area_opt
+                .filter(|area| {
+                    intent < 0.0 && rect.bottom <= area.top + TOP_MARGIN
+                })
+                .or(overlapping_area_opt)
+        } else {
+            None
+        };
+
+        self.is_climbing = climbing_area_opt.is_some();
+
+        if let Some(area) = climbing_area_opt {
+            ClimbUpdate::Climb {
+                x: area.center().x,
+                y: self.intent * self.speed,
+            }
+        } else if let Some(area) = top_area_opt {
+            // Land on top of the one-way-top climbable,
+            // if the entity would fall through its top this frame.
+            let next_bottom = rect.bottom + velocity_y * dt;
+            if dt > 0.0 && velocity_y <= 0.0 && next_bottom <= area.top {
+                self.is_on_top = true;
+                ClimbUpdate::Land {
+                    y: (area.top - rect.bottom) / dt,
+                }
+            } else {
+                ClimbUpdate::None
+            }
+        } else {
+            ClimbUpdate::None
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Test component `Climber`
+
+use super::*;
+
+const DT: f32 = 0.1;
+
+fn get_rect(top: f32, bottom: f32, left: f32, right: f32) -> Rect {
+    Rect::builder()
+        .top(top)
+        .bottom(bottom)
+        .left(left)
+        .right(right)
+        .build()
+        .unwrap()
+}
+
+/// A ladder from `y = -20` to `y = 0`, centered at `x = 0`.
+fn get_ladder() -> Rect {
+    get_rect(0.0, -20.0, -2.0, 2.0)
+}
+
+#[test]
+fn starts_climbing_only_with_intent_while_overlapping() {
+    let mut climber = Climber::new(3.0);
+    let ladder = get_ladder();
+    let inside = get_rect(-8.0, -10.0, 0.0, 1.0);
+    let outside = get_rect(-8.0, -10.0, 10.0, 11.0);
+
+    let update = climber.update(DT, &inside, 0.0, vec![(&ladder, false)]);
+    assert_eq!(update, ClimbUpdate::None, "No intent shouldn't climb");
+    assert!(!climber.is_climbing());
+
+    climber.set_intent(1.0);
+    let update = climber.update(DT, &outside, 0.0, vec![(&ladder, false)]);
+    assert_eq!(update, ClimbUpdate::None, "Not overlapping shouldn't climb");
+    assert!(!climber.is_climbing());
+
+    let update = climber.update(DT, &inside, 0.0, vec![(&ladder, false)]);
+    assert_eq!(update, ClimbUpdate::Climb { x: 0.0, y: 3.0 });
+    assert!(climber.is_climbing());
+}
+
+#[test]
+fn stands_on_one_way_top_and_climbs_down() {
+    let mut climber = Climber::new(3.0);
+    let ladder = get_ladder();
+
+    // Falls onto the ladder's top this frame.
+    let falling = get_rect(2.2, 0.2, -1.0, 1.0);
+    match climber.update(DT, &falling, -10.0, vec![(&ladder, true)]) {
+        ClimbUpdate::Land { y } => assert!((y - -2.0).abs() < 0.001),
+        update => panic!("Should land on top, but got {:?}", update),
+    }
+    assert!(climber.is_on_top());
+    assert!(!climber.is_climbing());
+
+    climber.set_intent(-1.0);
+    let standing = get_rect(2.0, 0.0, -1.0, 1.0);
+    let update = climber.update(DT, &standing, 0.0, vec![(&ladder, true)]);
+    assert_eq!(update, ClimbUpdate::Climb { x: 0.0, y: -3.0 });
+    assert!(climber.is_climbing());
+    assert!(!climber.is_on_top());
+}
+
+#[test]
+fn falling_past_top_margin_doesnt_land() {
+    let mut climber = Climber::new(3.0);
+    let ladder = get_ladder();
+    let below_top = get_rect(1.0, -1.0, -1.0, 1.0);
+
+    let update = climber.update(DT, &below_top, -10.0, vec![(&ladder, true)]);
+    assert_eq!(update, ClimbUpdate::None);
+    assert!(!climber.is_on_top());
+}
@@ -4,16 +4,23 @@ use crate::query::Query;
 use core::amethyst::ecs::world::Index;
 use std::collections::HashMap;
 
+/// A `Collider` checks for collisions with `Collidable` entities,
+/// in the `UpdateCollisionsSystem`.
+/// The optional `padding` is the tolerance used to detect touching entities,
+/// and to decide on which side a collision happened.
+/// If it is `None`, the `UpdateCollisionsSystem`'s default padding is used.
+/// For deciding the side, the padding is capped at a quarter
+/// of the hitbox's size, so tiny hitboxes still get correct sides.
 #[derive(Component, Deserialize, Clone)]
 #[storage(DenseVecStorage)]
-#[serde(deny_unknown_fields, from = "C")]
+#[serde(from = "ColliderData<C>")]
 pub struct Collider<C>
 where
     C: 'static + CollisionTag,
 {
-    pub tag:        C,
-    #[serde(skip, default = "default_collisions_data")]
-    pub collisions: HashMap<Index, CollisionData<C>>,
+    pub tag:            C,
+    pub(crate) padding: Option<(f32, f32)>,
+    pub collisions:     HashMap<Index, CollisionData<C>>,
 }
 
 impl<C> Collider<C>
@@ -23,10 +30,23 @@ where
     pub fn new(tag: C) -> Self {
         Self {
             tag,
+            padding: None,
             collisions: Default::default(),
         }
     }
 
+    /// Use the given side-detection padding for this collider,
+    /// instead of the `UpdateCollisionsSystem`'s default padding.
+    pub fn with_padding(mut self, padding: (f32, f32)) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Returns this collider's padding, if it has a custom padding.
+    pub fn padding(&self) -> Option<(f32, f32)> {
+        self.padding
+    }
+
     /// Returns a `Query` type for this collider.
     pub fn query<'a, Q>(&'a self) -> Q
     where
@@ -36,11 +56,14 @@ where
     }
 
     /// Is called when an entity is colliding with this entity.
+    /// `is_tile_map` is `true`, if the colliding entity's
+    /// `TileCollisionMap` cells are colliding.
     pub(crate) fn set_collision_with(
         &mut self,
         entity_id: Index,
         side: CollisionSide,
         tag: C,
+        is_tile_map: bool,
     ) {
         if let Some(data) = self.collisions.get_mut(&entity_id) {
             use CollisionState::*;
@@ -61,12 +84,14 @@ where
                     }
                 }
             };
+            data.is_tile_map = is_tile_map;
             data.did_update_collision = true;
         } else {
             self.collisions.insert(entity_id, CollisionData {
                 state:                CollisionState::Enter(side),
                 tag:                  tag,
                 id:                   entity_id,
+                is_tile_map:          is_tile_map,
                 did_update_collision: true,
             });
         }
@@ -101,16 +126,38 @@ where
     C: CollisionTag,
 {
     fn from(tag: C) -> Self {
-        Self {
-            tag,
-            collisions: default_collisions_data(),
-        }
+        Self::new(tag)
     }
 }
 
-fn default_collisions_data<C>() -> HashMap<Index, CollisionData<C>>
+/// A `Collider` can be deserialized from just its tag,
+/// or from a map with the tag and a custom padding.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ColliderData<C> {
+    Tag(C),
+    Full(FullColliderData<C>),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FullColliderData<C> {
+    tag:     C,
+    #[serde(default)]
+    padding: Option<(f32, f32)>,
+}
+
+impl<C> From<ColliderData<C>> for Collider<C>
 where
-    C: CollisionTag,
+    C: 'static + CollisionTag,
 {
-    HashMap::new()
+    fn from(data: ColliderData<C>) -> Self {
+        match data {
+            ColliderData::Tag(tag) => Self::new(tag),
+            ColliderData::Full(FullColliderData { tag, padding }) => Self {
+                padding,
+                ..Self::new(tag)
+            },
+        }
+    }
 }
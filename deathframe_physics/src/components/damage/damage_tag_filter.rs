@@ -0,0 +1,46 @@
+use super::component_prelude::*;
+
+/// `TakesDamage` entities with a `DamageTagFilter` don't take damage
+/// from collisions with any of its `ignored_tags`
+/// (for example to prevent friendly fire).
+#[derive(Component, Clone, Deserialize)]
+#[storage(DenseVecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct DamageTagFilter<C>
+where
+    C: 'static + CollisionTag,
+{
+    #[serde(default)]
+    pub(crate) ignored_tags: Vec<C>,
+}
+
+impl<C> DamageTagFilter<C>
+where
+    C: 'static + CollisionTag,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Don't take damage from collisions with the given tag.
+    pub fn with_ignored_tag(mut self, tag: C) -> Self {
+        self.ignored_tags.push(tag);
+        self
+    }
+
+    /// Returns `true` if damage from collisions with the given tag is ignored.
+    pub fn ignores_tag(&self, tag: &C) -> bool {
+        self.ignored_tags.contains(tag)
+    }
+}
+
+impl<C> Default for DamageTagFilter<C>
+where
+    C: 'static + CollisionTag,
+{
+    fn default() -> Self {
+        Self {
+            ignored_tags: Default::default(),
+        }
+    }
+}
@@ -6,12 +6,64 @@ use super::component_prelude::*;
 #[storage(VecStorage)]
 #[serde(deny_unknown_fields)]
 pub struct DealsDamage {
-    pub damage: HitPoints,
+    /// The damage dealt once, or per second,
+    /// depending on the `mode`.
+    pub damage:      HitPoints,
+    #[serde(default)]
+    pub mode:        DamageMode,
+    /// Velocity applied to the damaged entity, when it is hit.
+    /// On the axis of the collision side, the velocity is pointed away
+    /// from this entity; on the other axis it is applied as given.
+    /// Zero values are not applied.
+    #[serde(default)]
+    pub knockback:   Option<(f32, f32)>,
+    /// Optional type of damage, for `TakesDamage` resistances.
+    #[serde(default)]
+    pub damage_type: Option<String>,
 }
 
 impl DealsDamage {
     /// Returns a new `DealsDamage` with the given `damage`.
     pub fn new(damage: HitPoints) -> Self {
-        Self { damage }
+        Self {
+            damage,
+            mode: DamageMode::default(),
+            knockback: None,
+            damage_type: None,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: DamageMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_knockback(mut self, knockback: (f32, f32)) -> Self {
+        self.knockback = Some(knockback);
+        self
+    }
+
+    pub fn with_damage_type<S>(mut self, damage_type: S) -> Self
+    where
+        S: ToString,
+    {
+        self.damage_type = Some(damage_type.to_string());
+        self
+    }
+}
+
+/// How a `DealsDamage` entity deals its damage.
+#[derive(Clone, Deserialize, PartialEq, Debug)]
+pub enum DamageMode {
+    /// Deal the damage once, when the collision starts.
+    OnEnter,
+    /// Continuously deal the damage per second,
+    /// for as long as the collision persists.
+    PerSecond,
+}
+
+impl Default for DamageMode {
+    fn default() -> Self {
+        DamageMode::OnEnter
     }
 }
@@ -1,8 +1,13 @@
 pub mod prelude {
-    pub use super::deals_damage::DealsDamage;
+    pub use super::damage_tag_filter::DamageTagFilter;
+    pub use super::deals_damage::{DamageMode, DealsDamage};
     pub use super::takes_damage::TakesDamage;
 }
 
+#[cfg(test)]
+mod tests;
+
+mod damage_tag_filter;
 mod deals_damage;
 mod takes_damage;
 
@@ -1,8 +1,92 @@
 use super::component_prelude::*;
+use core::components::prelude::Invulnerable;
+use std::collections::HashMap;
 
 /// Makes the entity _take damage_ from entities it
 /// collides with, that have the `DealsDamage` component.
+/// Damage can be reduced by `resistances` for specific damage types.
+/// With `invulnerability`, the given `Invulnerable` component is added
+/// to the entity after it is hit, during which it doesn't take damage.
+/// To ignore damage from collisions with specific tags
+/// (for example to prevent friendly fire), add a `DamageTagFilter`.
+///
+/// Can be deserialized from the unit `TakesDamage` (or `()`),
+/// or from a map with its options. In code, create it with
+/// `TakesDamage::new()` (or `default()`), since it isn't a unit struct.
 #[derive(Component, Default, Clone, Deserialize)]
-#[storage(NullStorage)]
+#[storage(DenseVecStorage)]
+#[serde(from = "TakesDamageData")]
+pub struct TakesDamage {
+    /// Resistances per damage type.
+    /// `0.0` takes full damage, `0.5` takes half damage,
+    /// `1.0` (or more) takes no damage. Negative values increase damage.
+    pub(crate) resistances:     HashMap<String, f32>,
+    /// Added to the entity after it takes a hit.
+    pub(crate) invulnerability: Option<Invulnerable>,
+}
+
+impl TakesDamage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the resistance for the given damage type.
+    pub fn with_resistance<S>(mut self, damage_type: S, resistance: f32) -> Self
+    where
+        S: ToString,
+    {
+        self.resistances.insert(damage_type.to_string(), resistance);
+        self
+    }
+
+    /// Add the given `Invulnerable` to the entity, after it takes a hit.
+    pub fn with_invulnerability(
+        mut self,
+        invulnerability: Invulnerable,
+    ) -> Self {
+        self.invulnerability = Some(invulnerability);
+        self
+    }
+
+    /// Returns the factor, with which damage of the given type is multiplied.
+    pub fn damage_factor(&self, damage_type: &Option<String>) -> f32 {
+        damage_type
+            .as_ref()
+            .and_then(|damage_type| self.resistances.get(damage_type))
+            .map(|resistance| (1.0 - resistance).max(0.0))
+            .unwrap_or(1.0)
+    }
+}
+
+/// `TakesDamage` can be deserialized from a unit value,
+/// like it could before it had any options, or from a map.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TakesDamageData {
+    Unit,
+    Full(FullTakesDamageData),
+}
+
+#[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
-pub struct TakesDamage;
+struct FullTakesDamageData {
+    #[serde(default)]
+    resistances:     HashMap<String, f32>,
+    #[serde(default)]
+    invulnerability: Option<Invulnerable>,
+}
+
+impl From<TakesDamageData> for TakesDamage {
+    fn from(data: TakesDamageData) -> Self {
+        match data {
+            TakesDamageData::Unit => Self::new(),
+            TakesDamageData::Full(FullTakesDamageData {
+                resistances,
+                invulnerability,
+            }) => Self {
+                resistances,
+                invulnerability,
+            },
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Test components `DealsDamage`, `TakesDamage` and `DamageTagFilter`
+
+use super::prelude::*;
+use serde::de::value::{Error, UnitDeserializer};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+#[test]
+fn takes_full_damage_without_resistance() {
+    let takes_damage = TakesDamage::new().with_resistance("fire", 0.5);
+    assert_eq!(takes_damage.damage_factor(&None), 1.0);
+    assert_eq!(takes_damage.damage_factor(&Some("ice".to_string())), 1.0);
+}
+
+#[test]
+fn resistance_reduces_damage() {
+    let takes_damage = TakesDamage::new()
+        .with_resistance("fire", 0.25)
+        .with_resistance("lava", 1.0)
+        .with_resistance("poison", 2.0);
+    assert_eq!(takes_damage.damage_factor(&Some("fire".to_string())), 0.75);
+    assert_eq!(takes_damage.damage_factor(&Some("lava".to_string())), 0.0);
+    assert_eq!(takes_damage.damage_factor(&Some("poison".to_string())), 0.0);
+}
+
+#[test]
+fn negative_resistance_increases_damage() {
+    let takes_damage = TakesDamage::new().with_resistance("ice", -0.5);
+    assert_eq!(takes_damage.damage_factor(&Some("ice".to_string())), 1.5);
+}
+
+#[test]
+fn ignores_tags() {
+    assert!(!DamageTagFilter::<()>::new().ignores_tag(&()));
+    assert!(DamageTagFilter::new().with_ignored_tag(()).ignores_tag(&()));
+}
+
+#[test]
+fn takes_damage_deserializes_from_unit() {
+    let deserializer: UnitDeserializer<Error> = ().into_deserializer();
+    let takes_damage = TakesDamage::deserialize(deserializer).unwrap();
+    assert!(takes_damage.resistances.is_empty());
+    assert!(takes_damage.invulnerability.is_none());
+}
+
+#[test]
+fn deals_damage_on_enter_by_default() {
+    let deals_damage = DealsDamage::new(2).with_damage_type("fire");
+    assert_eq!(deals_damage.mode, DamageMode::OnEnter);
+    assert_eq!(deals_damage.damage_type, Some("fire".to_string()));
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+use core::components::prelude::Size;
+
+/// A `Hitbox` has one or more `Rect` rects,
+/// which are collision boxes, relative to this entity's `Transform`.
+/// So the `Rect` rects assume the entity's position is at `0, 0`.
+///
+/// A `Hitbox` can opt-in to be mirrored on the X and/or Y axis,
+/// for asymmetric hitboxes of entities that can face both directions.
+/// The `UpdateHitboxMirrorSystem` mirrors the rects around the
+/// entity's position, if the entity has a `Facing` component
+/// facing left (or down), or if it has a negative `Transform` scale.
+/// A `Solid` entity's hitbox is only mirrored, when the mirrored rects
+/// wouldn't be in collision with another solid.
+#[derive(Debug, Component, Default, Deserialize, Clone)]
+#[storage(DenseVecStorage)]
+#[serde(from = "HitboxData")]
+pub struct Hitbox {
+    pub(crate) rects:    Vec<Rect>,
+    pub(crate) mirror:   (bool, bool),
+    pub(crate) mirrored: (bool, bool),
+}
+
+impl Hitbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rect(mut self, rect: Rect) -> Self {
+        self.add_rect(rect);
+        self
+    }
+
+    pub fn with_rects(mut self, rects: Vec<Rect>) -> Self {
+        self.rects = rects;
+        Axis::for_each(|axis| {
+            if self.mirrored.by_axis(&axis) {
+                self.rects
+                    .iter_mut()
+                    .for_each(|rect| mirror_rect(rect, &axis));
+            }
+        });
+        self
+    }
+
+    /// Enable mirroring of this hitbox's rects on the X axis.
+    pub fn with_mirror_x(mut self, mirror_x: bool) -> Self {
+        self.mirror.0 = mirror_x;
+        self
+    }
+
+    /// Enable mirroring of this hitbox's rects on the Y axis.
+    pub fn with_mirror_y(mut self, mirror_y: bool) -> Self {
+        self.mirror.1 = mirror_y;
+        self
+    }
+
+    /// Adds the given rect, which is mirrored if this hitbox
+    /// is currently mirrored.
+    pub fn add_rect(&mut self, mut rect: Rect) {
+        Axis::for_each(|axis| {
+            if self.mirrored.by_axis(&axis) {
+                mirror_rect(&mut rect, &axis);
+            }
+        });
+        self.rects.push(rect);
+    }
+
+    /// Returns `true` if mirroring is enabled for the given `Axis`.
+    pub fn mirrors(&self, axis: &Axis) -> bool {
+        self.mirror.by_axis(axis)
+    }
+
+    /// Returns `true` if the rects are currently mirrored on the given `Axis`.
+    pub fn is_mirrored(&self, axis: &Axis) -> bool {
+        self.mirrored.by_axis(axis)
+    }
+
+    /// Mirror or un-mirror the rects on the given `Axis`.
+    /// Does nothing if mirroring is not enabled for the axis,
+    /// or if the rects are already in the given state.
+    pub(crate) fn set_mirrored(&mut self, axis: &Axis, mirrored: bool) {
+        if self.mirrors(axis) && self.is_mirrored(axis) != mirrored {
+            self.rects
+                .iter_mut()
+                .for_each(|rect| mirror_rect(rect, axis));
+            *(&mut self.mirrored).by_axis(axis) = mirrored;
+        }
+    }
+}
+
+/// Mirrors the given (relative) rect around the origin, on the given axis.
+fn mirror_rect(rect: &mut Rect, axis: &Axis) {
+    match axis {
+        Axis::X => {
+            let left = rect.left;
+            rect.left = -rect.right;
+            rect.right = -left;
+        }
+        Axis::Y => {
+            let bottom = rect.bottom;
+            rect.bottom = -rect.top;
+            rect.top = -bottom;
+        }
+    }
+}
+
+/// A `Hitbox` can be deserialized from just a list of rects,
+/// or from a map with the rects and the mirror flags.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HitboxData {
+    Rects(Vec<Rect>),
+    Full(FullHitboxData),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FullHitboxData {
+    rects:    Vec<Rect>,
+    #[serde(default)]
+    mirror_x: bool,
+    #[serde(default)]
+    mirror_y: bool,
+}
+
+impl From<HitboxData> for Hitbox {
+    fn from(data: HitboxData) -> Self {
+        match data {
+            HitboxData::Rects(rects) => Self::from(rects),
+            HitboxData::Full(FullHitboxData {
+                rects,
+                mirror_x,
+                mirror_y,
+            }) => Self::from(rects)
+                .with_mirror_x(mirror_x)
+                .with_mirror_y(mirror_y),
+        }
+    }
+}
+
+impl From<Vec<Rect>> for Hitbox {
+    fn from(rects: Vec<Rect>) -> Self {
+        Self::default().with_rects(rects)
+    }
+}
+
+impl From<Rect> for Hitbox {
+    fn from(rect: Rect) -> Self {
+        Self::default().with_rect(rect)
+    }
+}
+
+impl From<&Size> for Hitbox {
+    fn from(size: &Size) -> Self {
+        Self::default().with_rect(Rect::from(size))
+    }
+}
+
+impl Into<Vec<Rect>> for Hitbox {
+    fn into(self) -> Vec<Rect> {
+        self.rects
+    }
+}
+
+impl<'a> Into<&'a Vec<Rect>> for &'a Hitbox {
+    fn into(self) -> &'a Vec<Rect> {
+        &self.rects
+    }
+}
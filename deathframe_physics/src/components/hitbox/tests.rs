@@ -0,0 +1,59 @@
+//! Test component `Hitbox`
+
+use super::*;
+
+fn get_rect() -> Rect {
+    Rect::builder()
+        .top(4.0)
+        .bottom(-2.0)
+        .left(1.0)
+        .right(8.0)
+        .build()
+        .unwrap()
+}
+
+fn sides(hitbox: &Hitbox) -> (f32, f32, f32, f32) {
+    let rect = &hitbox.rects[0];
+    (rect.top, rect.bottom, rect.left, rect.right)
+}
+
+#[test]
+fn mirrors_rects_on_x() {
+    let mut hitbox = Hitbox::from(get_rect()).with_mirror_x(true);
+    hitbox.set_mirrored(&Axis::X, true);
+    assert!(hitbox.is_mirrored(&Axis::X));
+    assert_eq!(sides(&hitbox), (4.0, -2.0, -8.0, -1.0));
+}
+
+#[test]
+fn mirrors_rects_on_y() {
+    let mut hitbox = Hitbox::from(get_rect()).with_mirror_y(true);
+    hitbox.set_mirrored(&Axis::Y, true);
+    assert_eq!(sides(&hitbox), (2.0, -4.0, 1.0, 8.0));
+}
+
+#[test]
+fn unmirroring_restores_rects() {
+    let mut hitbox = Hitbox::from(get_rect()).with_mirror_x(true);
+    hitbox.set_mirrored(&Axis::X, true);
+    hitbox.set_mirrored(&Axis::X, true);
+    hitbox.set_mirrored(&Axis::X, false);
+    assert!(!hitbox.is_mirrored(&Axis::X));
+    assert_eq!(sides(&hitbox), (4.0, -2.0, 1.0, 8.0));
+}
+
+#[test]
+fn does_not_mirror_without_opt_in() {
+    let mut hitbox = Hitbox::from(get_rect());
+    hitbox.set_mirrored(&Axis::X, true);
+    assert!(!hitbox.is_mirrored(&Axis::X));
+    assert_eq!(sides(&hitbox), (4.0, -2.0, 1.0, 8.0));
+}
+
+#[test]
+fn added_rects_are_mirrored() {
+    let mut hitbox = Hitbox::new().with_mirror_x(true);
+    hitbox.set_mirrored(&Axis::X, true);
+    hitbox.add_rect(get_rect());
+    assert_eq!(sides(&hitbox), (4.0, -2.0, -8.0, -1.0));
+}
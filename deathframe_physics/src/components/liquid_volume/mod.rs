@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+use crate::collision::collision_check;
+
+/// A `LiquidVolume` is an area of liquid (water, lava, ...),
+/// relative to this entity's `Transform`.
+/// Entities with `Velocity`, which overlap with this area,
+/// are slowed down and float, with the `ApplyLiquidsSystem`.
+#[derive(Component, Builder, Clone, Debug, Deserialize)]
+#[storage(VecStorage)]
+#[builder(pattern = "owned")]
+#[serde(deny_unknown_fields)]
+pub struct LiquidVolume {
+    /// The liquid's area, relative to this entity's position.
+    pub(crate) rect:    Rect,
+    /// How much of an entity's `Gravity` is counteracted,
+    /// when it is fully submerged. `1.0` cancels out gravity completely,
+    /// values above `1.0` make entities float up to the surface.
+    #[builder(default = "1.0")]
+    #[serde(default = "default_density")]
+    pub(crate) density: f32,
+    /// Drag that is applied to fully submerged entities' velocities.
+    /// Partially submerged entities receive proportionally less drag.
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) drag:    f32,
+    /// The y position of the liquid's surface line,
+    /// relative to this entity's position.
+    /// Defaults to the top of the `rect`.
+    #[builder(setter(strip_option), default)]
+    #[serde(default)]
+    pub(crate) surface: Option<f32>,
+}
+
+impl LiquidVolume {
+    /// Returns a new `LiquidVolumeBuilder`.
+    pub fn builder() -> LiquidVolumeBuilder {
+        LiquidVolumeBuilder::default()
+    }
+
+    /// Returns the absolute y position of the liquid's surface line,
+    /// for the given position of this liquid entity.
+    pub fn surface_at(&self, liquid_pos: &Point) -> f32 {
+        liquid_pos.y + self.surface.unwrap_or(self.rect.top)
+    }
+
+    /// Returns how deep the given absolute `Rect` is submerged in this liquid,
+    /// for the given position of this liquid entity.
+    /// `0.0` means it isn't submerged at all, `1.0` means fully submerged.
+    pub fn submersion(&self, liquid_pos: &Point, rect: &Rect) -> f32 {
+        let area = self.rect.clone().with_offset(liquid_pos);
+        if !collision_check::do_rects_intersect(rect, &area) {
+            return 0.0;
+        }
+
+        let surface = self.surface_at(liquid_pos);
+        let height = rect.top - rect.bottom;
+        if height > 0.0 {
+            ((surface - rect.bottom) / height).min(1.0).max(0.0)
+        } else if rect.bottom < surface {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+fn default_density() -> f32 {
+    1.0
+}
@@ -0,0 +1,82 @@
+//! Test component `LiquidVolume`
+
+use super::*;
+
+fn get_liquid() -> LiquidVolume {
+    LiquidVolume::builder()
+        .rect(
+            Rect::builder()
+                .top(10.0)
+                .bottom(-10.0)
+                .left(-10.0)
+                .right(10.0)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
+fn get_rect(top: f32, bottom: f32) -> Rect {
+    Rect::builder()
+        .top(top)
+        .bottom(bottom)
+        .left(-1.0)
+        .right(1.0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn built_liquid_has_default_values() {
+    let liquid = get_liquid();
+    assert_eq!(liquid.density, 1.0, "Default density should be 1.0");
+    assert_eq!(liquid.drag, 0.0, "Default drag should be 0.0");
+    assert_eq!(
+        liquid.surface_at(&Point::new(0.0, 5.0)),
+        15.0,
+        "Surface should default to the top of the rect"
+    );
+}
+
+#[test]
+fn rect_outside_is_not_submerged() {
+    let liquid = get_liquid();
+    let pos = Point::new(0.0, 0.0);
+    assert_eq!(liquid.submersion(&pos, &get_rect(30.0, 20.0)), 0.0);
+    assert_eq!(liquid.submersion(&pos, &get_rect(-20.0, -30.0)), 0.0);
+}
+
+#[test]
+fn rect_is_partially_submerged() {
+    let liquid = get_liquid();
+    let pos = Point::new(0.0, 0.0);
+    assert_eq!(
+        liquid.submersion(&pos, &get_rect(12.0, 8.0)),
+        0.5,
+        "Half of the rect should be submerged"
+    );
+}
+
+#[test]
+fn rect_is_fully_submerged() {
+    let liquid = get_liquid();
+    let pos = Point::new(0.0, 0.0);
+    assert_eq!(
+        liquid.submersion(&pos, &get_rect(2.0, -2.0)),
+        1.0,
+        "Rect below surface should be fully submerged"
+    );
+}
+
+#[test]
+fn rect_above_custom_surface_is_not_submerged() {
+    let liquid = LiquidVolume::builder()
+        .rect(get_rect(10.0, -10.0))
+        .surface(0.0)
+        .build()
+        .unwrap();
+    let pos = Point::new(0.0, 0.0);
+    assert_eq!(liquid.submersion(&pos, &get_rect(8.0, 4.0)), 0.0);
+    assert_eq!(liquid.submersion(&pos, &get_rect(2.0, -2.0)), 0.5);
+}
@@ -1,17 +1,23 @@
 pub mod prelude {
     pub use super::base_friction::BaseFriction;
+    pub use super::climbable::Climbable;
+    pub use super::climber::Climber;
     pub use super::collidable::Collidable;
     pub use super::collider::Collider;
     pub use super::damage::prelude::*;
     pub use super::gravity::Gravity;
     pub use super::hitbox::Hitbox;
+    pub use super::liquid_volume::LiquidVolume;
     pub use super::non_precise_movement::NonPreciseMovement;
     pub use super::solid::Solid;
     pub use super::solid_pushable::SolidPushable;
     pub use super::solid_pusher::SolidPusher;
+    pub use super::tile_collision_map::TileCollisionMap;
     pub use super::velocity::Velocity;
 }
 
+pub(crate) use climber::ClimbUpdate;
+
 mod component_prelude {
     pub(super) use super::helpers::WithCollisionTag;
     pub(super) use crate::collision::tag::CollisionTag;
@@ -19,15 +25,19 @@ mod component_prelude {
 }
 
 mod base_friction;
+mod climbable;
+mod climber;
 mod collidable;
 mod collider;
 mod damage;
 mod gravity;
 mod hitbox;
+mod liquid_volume;
 mod non_precise_movement;
 mod solid;
 mod solid_pushable;
 mod solid_pusher;
+mod tile_collision_map;
 mod velocity;
 
 pub mod helpers {
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+
+/// With this component, moving entities only move by
+/// whole integer numbers. The velocity's float remainder
+/// is not moved by, but carried over to the next frame,
+/// so the entity's average speed still matches its `Velocity`.
+/// Use this for entities that should try to snap
+/// to the nearest integer position.
+///
+/// The carried over remainder is per-entity state, which has to
+/// persist between frames, so this isn't a zero-sized marker component.
+/// Most moving entities in a pixel-art game have it,
+/// so it uses a `VecStorage`.
+///
+/// Can be deserialized from the unit `NonPreciseMovement` (or `()`),
+/// or from a map with the `snap_to_nearest` option.
+#[derive(Component, Default, Deserialize, Clone)]
+#[storage(VecStorage)]
+#[serde(from = "NonPreciseMovementData")]
+pub struct NonPreciseMovement {
+    /// If `true`, entities which are not positioned on a whole pixel,
+    /// are moved to the nearest whole pixel, when moving towards it.
+    pub(crate) snap_to_nearest: bool,
+    pub(crate) remainder:       (f32, f32),
+}
+
+impl NonPreciseMovement {
+    /// Create a new `NonPreciseMovement` component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set if the entity should snap to the nearest whole pixel,
+    /// when it isn't positioned on a whole pixel.
+    /// Disabled by default.
+    pub fn with_snap_to_nearest(mut self, snap_to_nearest: bool) -> Self {
+        self.snap_to_nearest = snap_to_nearest;
+        self
+    }
+
+    /// Returns the carried over float remainder for the given `Axis`.
+    pub fn remainder(&self, axis: &Axis) -> f32 {
+        *(&self.remainder).by_axis(axis)
+    }
+
+    /// Clears the carried over remainder for the given `Axis`.
+    pub(crate) fn clear_remainder(&mut self, axis: &Axis) {
+        *(&mut self.remainder).by_axis(axis) = 0.0;
+    }
+
+    /// Adds the given step to the carried over remainder of the given `Axis`,
+    /// and returns the whole pixels which should be moved by.
+    /// The new float remainder is carried over to the next call.
+    /// A step of `0.0` clears the remainder, so stopped entities
+    /// don't keep a remainder around.
+    pub(crate) fn accumulate(&mut self, axis: &Axis, step: f32) -> f32 {
+        let remainder = (&mut self.remainder).by_axis(axis);
+        if step == 0.0 {
+            *remainder = 0.0;
+            return 0.0;
+        }
+        let total = *remainder + step;
+        let whole = total.trunc();
+        *remainder = total - whole;
+        whole
+    }
+}
+
+/// A `NonPreciseMovement` can be deserialized from a unit value,
+/// like it could before it had any options, or from a map.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NonPreciseMovementData {
+    Unit,
+    Full(FullNonPreciseMovementData),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FullNonPreciseMovementData {
+    #[serde(default)]
+    snap_to_nearest: bool,
+}
+
+impl From<NonPreciseMovementData> for NonPreciseMovement {
+    fn from(data: NonPreciseMovementData) -> Self {
+        match data {
+            NonPreciseMovementData::Unit => Self::new(),
+            NonPreciseMovementData::Full(FullNonPreciseMovementData {
+                snap_to_nearest,
+            }) => Self::new().with_snap_to_nearest(snap_to_nearest),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Test component `NonPreciseMovement`
+
+use super::*;
+use serde::de::value::{Error, MapDeserializer, UnitDeserializer};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+use std::iter::{self, Once};
+
+#[test]
+fn slow_movement_accumulates_to_whole_pixels() {
+    let mut non_precise = NonPreciseMovement::new();
+    let step = 20.0 * (1.0 / 60.0);
+    let mut moved = 0.0;
+
+    for _ in 0 .. 60 {
+        let whole = non_precise.accumulate(&Axis::X, step);
+        assert_eq!(whole % 1.0, 0.0, "Should only move by whole pixels");
+        moved += whole;
+    }
+
+    assert_eq!(moved, 20.0, "Should move 20 pixels in 60 frames");
+}
+
+#[test]
+fn negative_movement_accumulates_to_whole_pixels() {
+    let mut non_precise = NonPreciseMovement::new();
+    let mut moved = 0.0;
+
+    for _ in 0 .. 4 {
+        moved += non_precise.accumulate(&Axis::Y, -0.5);
+    }
+
+    assert_eq!(moved, -2.0, "Should move -2 pixels");
+    assert_eq!(non_precise.remainder(&Axis::Y), 0.0);
+    assert_eq!(
+        non_precise.remainder(&Axis::X),
+        0.0,
+        "Other axis' remainder should be unaffected"
+    );
+}
+
+#[test]
+fn zero_step_clears_remainder() {
+    let mut non_precise = NonPreciseMovement::new();
+
+    assert_eq!(non_precise.accumulate(&Axis::X, 0.75), 0.0);
+    assert_eq!(non_precise.remainder(&Axis::X), 0.75);
+    assert_eq!(non_precise.accumulate(&Axis::X, 0.0), 0.0);
+    assert_eq!(
+        non_precise.remainder(&Axis::X),
+        0.0,
+        "Remainder should be cleared when not moving"
+    );
+}
+
+#[test]
+fn deserializes_from_unit() {
+    let deserializer: UnitDeserializer<Error> = ().into_deserializer();
+    let non_precise = NonPreciseMovement::deserialize(deserializer).unwrap();
+    assert!(!non_precise.snap_to_nearest);
+}
+
+#[test]
+fn deserializes_from_map() {
+    let non_precise =
+        NonPreciseMovement::deserialize(map_deserializer("snap_to_nearest"))
+            .unwrap();
+    assert!(non_precise.snap_to_nearest);
+}
+
+#[test]
+fn rejects_unknown_fields() {
+    assert!(
+        NonPreciseMovement::deserialize(map_deserializer("snap_to_neares"))
+            .is_err()
+    );
+}
+
+/// Returns a deserializer for a map with the given key set to `true`.
+fn map_deserializer(
+    key: &'static str,
+) -> MapDeserializer<'static, Once<(&'static str, bool)>, Error> {
+    MapDeserializer::new(iter::once((key, true)))
+}
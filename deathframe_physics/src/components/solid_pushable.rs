@@ -1,6 +1,37 @@
 use super::component_prelude::*;
 
-#[derive(Component, Default, Deserialize, Clone)]
-#[storage(NullStorage)]
+/// Solid entities with `SolidPushable` can be pushed by `SolidPusher`s.
+/// A pusher can only push pushables, whose total `weight`
+/// doesn't exceed the pusher's `strength`.
+#[derive(Component, Deserialize, Clone)]
+#[storage(VecStorage)]
 #[serde(deny_unknown_fields)]
-pub struct SolidPushable;
+pub struct SolidPushable {
+    #[serde(default = "default_weight")]
+    pub(crate) weight: f32,
+}
+
+impl SolidPushable {
+    /// Create a new `SolidPushable` with the default weight of `1.0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the weight of this pushable entity.
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl Default for SolidPushable {
+    fn default() -> Self {
+        Self {
+            weight: default_weight(),
+        }
+    }
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
@@ -1,6 +0,0 @@
-use super::component_prelude::*;
-
-#[derive(Component, Default, Deserialize, Clone)]
-#[storage(NullStorage)]
-#[serde(deny_unknown_fields)]
-pub struct SolidPusher;
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+
+/// Solid entities with `SolidPusher` push `SolidPushable` entities
+/// out of their way, when moving into them.
+/// If a pushed entity can't be moved, because it is squeezed between
+/// this entity and an immovable solid, it is _crushed_.
+/// See the `MoveEntitiesSystem` for details.
+#[derive(Component, Default, Deserialize, Clone)]
+#[storage(VecStorage)]
+#[serde(deny_unknown_fields)]
+pub struct SolidPusher {
+    /// Damage to deal to entities crushed by this pusher,
+    /// through their `HealthActionQueue`, once per crush.
+    #[serde(default)]
+    pub(crate) crush_damage:     Option<HitPoints>,
+    /// The maximum total weight of `SolidPushable`s this entity can push
+    /// at once, including chains of pushables pushing each other.
+    /// Can push any weight, if `None`.
+    #[serde(default)]
+    pub(crate) strength:         Option<f32>,
+    /// If `true`, this entity is slowed down when pushing, depending on
+    /// the pushed weight. Pushing a weight equal to its `strength`
+    /// halves its speed. Only has an effect with a `strength`.
+    /// The slowdown uses the weight pushed in the previous frame,
+    /// so it starts (and stops) one frame after the push does.
+    #[serde(default)]
+    pub(crate) slowed_by_weight: bool,
+    #[serde(skip)]
+    pub(crate) pushed_weight:    (f32, f32),
+}
+
+impl SolidPusher {
+    /// Create a new `SolidPusher`, which can push any weight,
+    /// and doesn't deal crush damage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deal the given damage to crushed entities, once when
+    /// they start being crushed by this pusher.
+    pub fn with_crush_damage(mut self, crush_damage: HitPoints) -> Self {
+        self.crush_damage = Some(crush_damage);
+        self
+    }
+
+    /// Set the maximum total weight this entity can push.
+    pub fn with_strength(mut self, strength: f32) -> Self {
+        self.strength = Some(strength);
+        self
+    }
+
+    /// Set if this entity should be slowed down, when pushing heavy objects.
+    pub fn with_slowed_by_weight(mut self, slowed_by_weight: bool) -> Self {
+        self.slowed_by_weight = slowed_by_weight;
+        self
+    }
+
+    /// Returns the total weight this entity pushed on the given `Axis`,
+    /// the last time it moved on that axis.
+    pub fn pushed_weight(&self, axis: &Axis) -> f32 {
+        *(&self.pushed_weight).by_axis(axis)
+    }
+
+    pub(crate) fn set_pushed_weight(&mut self, axis: &Axis, weight: f32) {
+        *(&mut self.pushed_weight).by_axis(axis) = weight;
+    }
+
+    /// Returns the factor this entity's movement on the given `Axis`
+    /// is multiplied with, depending on the weight it is pushing.
+    /// Uses the `pushed_weight` from the last time it moved,
+    /// because the weight of this frame's push is only known after moving.
+    pub(crate) fn speed_factor(&self, axis: &Axis) -> f32 {
+        match self.strength {
+            Some(strength) if self.slowed_by_weight => {
+                let total = strength + self.pushed_weight(axis);
+                if total > 0.0 {
+                    strength / total
+                } else {
+                    1.0
+                }
+            }
+            _ => 1.0,
+        }
+    }
+}
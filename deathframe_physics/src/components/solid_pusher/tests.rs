@@ -0,0 +1,33 @@
+//! Test component `SolidPusher`
+
+use super::*;
+
+#[test]
+fn is_not_slowed_down_by_default() {
+    let mut pusher = SolidPusher::new().with_strength(2.0);
+    pusher.set_pushed_weight(&Axis::X, 2.0);
+    assert_eq!(pusher.speed_factor(&Axis::X), 1.0);
+}
+
+#[test]
+fn is_not_slowed_down_without_strength() {
+    let mut pusher = SolidPusher::new().with_slowed_by_weight(true);
+    pusher.set_pushed_weight(&Axis::X, 2.0);
+    assert_eq!(pusher.speed_factor(&Axis::X), 1.0);
+}
+
+#[test]
+fn is_slowed_down_by_pushed_weight_per_axis() {
+    let mut pusher = SolidPusher::new()
+        .with_strength(2.0)
+        .with_slowed_by_weight(true);
+    assert_eq!(pusher.speed_factor(&Axis::X), 1.0, "Not pushing anything");
+
+    pusher.set_pushed_weight(&Axis::X, 2.0);
+    assert_eq!(
+        pusher.speed_factor(&Axis::X),
+        0.5,
+        "Pushing a weight equal to the strength should halve the speed"
+    );
+    assert_eq!(pusher.speed_factor(&Axis::Y), 1.0, "Other axis is unchanged");
+}
@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod tests;
+
+use super::component_prelude::*;
+use crate::collision::collision_check;
+use crate::collision::rect::CollisionRect;
+use core::amethyst::ecs::world::Index;
+use std::convert::TryFrom;
+use std::ops::Range;
+
+/// A dense grid of solid tiles, for large static levels.
+/// Instead of giving every wall tile its own `Solid` and `Hitbox` entity,
+/// a single entity with a `TileCollisionMap` can hold all of them.
+/// Solid cells are treated exactly like `Solid` (and `Collidable`) rects
+/// with this map's `tag`, by the `MoveEntitiesSystem` and the
+/// `UpdateCollisionsSystem`. Collisions with any of the cells are
+/// registered as collisions with this map's entity,
+/// so they can be queried like any other collision;
+/// `QueryExpression::IsTileMap` matches only these collisions.
+///
+/// Cells are stored row by row, starting at the bottom-left cell,
+/// which is positioned at the `origin` (its bottom-left corner).
+/// The `origin` is an absolute position; unlike `Hitbox` rects,
+/// the cells are _not_ positioned relative to the entity's `Transform`,
+/// which is ignored (the entity doesn't need one), so moving the entity
+/// doesn't move the map.
+/// When deserializing, the amount of `cells` must be `columns * rows`.
+#[derive(Component, Clone, Deserialize)]
+#[storage(HashMapStorage)]
+#[serde(try_from = "TileCollisionMapData<C>")]
+pub struct TileCollisionMap<C>
+where
+    C: 'static + CollisionTag,
+{
+    pub(crate) tag:       C,
+    pub(crate) origin:    (f32, f32),
+    pub(crate) tile_size: (f32, f32),
+    pub(crate) columns:   usize,
+    pub(crate) rows:      usize,
+    pub(crate) cells:     Vec<bool>,
+}
+
+impl<C> TileCollisionMap<C>
+where
+    C: 'static + CollisionTag,
+{
+    /// Create a new `TileCollisionMap` with the given amount of
+    /// `columns` and `rows`, where no cells are solid.
+    pub fn new(
+        tag: C,
+        origin: (f32, f32),
+        tile_size: (f32, f32),
+        columns: usize,
+        rows: usize,
+    ) -> Self {
+        Self {
+            tag,
+            origin,
+            tile_size,
+            columns,
+            rows,
+            cells: vec![false; columns * rows],
+        }
+    }
+
+    /// Set the solid cells from the given rows, starting with the _top_ row,
+    /// as they would be laid out visually in a level file.
+    /// Replaces the map's dimensions with the dimensions of the given rows.
+    pub fn with_rows_from_top(mut self, rows: Vec<Vec<bool>>) -> Self {
+        self.rows = rows.len();
+        self.columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        self.cells = vec![false; self.columns * self.rows];
+        for (row_from_top, row) in rows.into_iter().enumerate() {
+            let row_idx = self.rows - 1 - row_from_top;
+            for (column, solid) in row.into_iter().enumerate() {
+                self.set_solid(column, row_idx, solid);
+            }
+        }
+        self
+    }
+
+    /// Returns the collision tag of this map's solid cells.
+    pub fn tag(&self) -> &C {
+        &self.tag
+    }
+
+    /// Returns the absolute position of the bottom-left cell's
+    /// bottom-left corner.
+    pub fn origin(&self) -> (f32, f32) {
+        self.origin
+    }
+
+    /// Returns the width and height of a single cell.
+    pub fn tile_size(&self) -> (f32, f32) {
+        self.tile_size
+    }
+
+    /// Returns the amount of columns and rows of this map.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// Set the solid state of the cell at the given column and row.
+    /// Does nothing, if the cell is outside of the map.
+    pub fn set_solid(&mut self, column: usize, row: usize, solid: bool) {
+        if let Some(cell) = self
+            .index(column, row)
+            .and_then(|idx| self.cells.get_mut(idx))
+        {
+            *cell = solid;
+        }
+    }
+
+    /// Returns `true` if the cell at the given column and row is solid.
+    /// Cells outside of the map are never solid.
+    pub fn is_solid(&self, column: usize, row: usize) -> bool {
+        self.index(column, row)
+            .and_then(|idx| self.cells.get(idx))
+            .cloned()
+            .unwrap_or(false)
+    }
+
+    /// Returns the absolute `Rect` of the cell at the given column and row.
+    pub fn cell_rect(&self, column: usize, row: usize) -> Rect {
+        let left = self.origin.0 + column as f32 * self.tile_size.0;
+        let bottom = self.origin.1 + row as f32 * self.tile_size.1;
+        Rect {
+            top: bottom + self.tile_size.1,
+            bottom,
+            left,
+            right: left + self.tile_size.0,
+        }
+    }
+
+    /// Returns the `Rect`s of all solid cells, which intersect
+    /// with the given absolute `Rect`.
+    pub fn solid_rects_in(&self, rect: &Rect) -> Vec<Rect> {
+        let (columns, rows) = (
+            self.cell_range(rect.left, rect.right, Axis::X),
+            self.cell_range(rect.bottom, rect.top, Axis::Y),
+        );
+        let mut solid_rects = Vec::new();
+        for row in rows {
+            for column in columns.clone() {
+                if self.is_solid(column, row) {
+                    let cell_rect = self.cell_rect(column, row);
+                    if collision_check::do_rects_intersect(rect, &cell_rect) {
+                        solid_rects.push(cell_rect);
+                    }
+                }
+            }
+        }
+        solid_rects
+    }
+
+    /// Returns `true` if any of the given `CollisionRect`'s rects
+    /// are in collision with a solid cell, and if their tags
+    /// allow them to collide with each other.
+    pub fn collides_with<T>(&self, target: &CollisionRect<C, T>) -> bool {
+        collision_check::do_rect_tags_match(&target.tag, &self.tag)
+            && target
+                .rects
+                .iter()
+                .any(|rect| !self.solid_rects_in(rect).is_empty())
+    }
+
+    /// Returns a `CollisionRect` with the given ID (of this map's entity),
+    /// holding all solid cells which intersect with the given `Rect`.
+    /// The cells' rects are grown by the given optional padding.
+    /// Returns `None` if no solid cells intersect.
+    pub fn gen_collision_rect(
+        &self,
+        id: Index,
+        rect: &Rect,
+        padding_opt: &Option<Point>,
+    ) -> Option<CollisionRect<C, ()>> {
+        let query_rect = if let Some(padding) = padding_opt {
+            rect.clone().with_padding(padding)
+        } else {
+            rect.clone()
+        };
+        let rects = self
+            .solid_rects_in(&query_rect)
+            .into_iter()
+            .map(|cell_rect| {
+                if let Some(padding) = padding_opt {
+                    cell_rect.with_padding(padding)
+                } else {
+                    cell_rect
+                }
+            })
+            .collect::<Vec<_>>();
+        if rects.is_empty() {
+            None
+        } else {
+            Some(CollisionRect {
+                id,
+                rects,
+                tag: self.tag.clone(),
+                custom: None,
+            })
+        }
+    }
+
+    fn index(&self, column: usize, row: usize) -> Option<usize> {
+        if column < self.columns && row < self.rows {
+            Some(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the range of cell indices on the given `Axis`,
+    /// which the given absolute `from` and `to` positions cover.
+    fn cell_range(&self, from: f32, to: f32, axis: Axis) -> Range<usize> {
+        let (origin, tile_size, max) = match axis {
+            Axis::X => (self.origin.0, self.tile_size.0, self.columns),
+            Axis::Y => (self.origin.1, self.tile_size.1, self.rows),
+        };
+        if tile_size <= 0.0 {
+            return 0 .. 0;
+        }
+        let start = ((from - origin) / tile_size).floor().max(0.0) as usize;
+        let end = ((to - origin) / tile_size).ceil().max(0.0) as usize;
+        start.min(max) .. end.min(max)
+    }
+}
+
+impl<C> WithCollisionTag<C> for TileCollisionMap<C>
+where
+    C: CollisionTag,
+{
+    fn collision_tag(&self) -> &C {
+        &self.tag
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TileCollisionMapData<C> {
+    tag:       C,
+    origin:    (f32, f32),
+    tile_size: (f32, f32),
+    columns:   usize,
+    rows:      usize,
+    cells:     Vec<bool>,
+}
+
+impl<C> TryFrom<TileCollisionMapData<C>> for TileCollisionMap<C>
+where
+    C: 'static + CollisionTag,
+{
+    type Error = String;
+
+    fn try_from(data: TileCollisionMapData<C>) -> Result<Self, Self::Error> {
+        if data.cells.len() != data.columns * data.rows {
+            return Err(format!(
+                "TileCollisionMap has {} cells, but should have {} \
+                 ({} columns * {} rows)",
+                data.cells.len(),
+                data.columns * data.rows,
+                data.columns,
+                data.rows,
+            ));
+        }
+        Ok(Self {
+            tag:       data.tag,
+            origin:    data.origin,
+            tile_size: data.tile_size,
+            columns:   data.columns,
+            rows:      data.rows,
+            cells:     data.cells,
+        })
+    }
+}
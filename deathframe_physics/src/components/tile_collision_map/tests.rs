@@ -0,0 +1,103 @@
+//! Test component `TileCollisionMap`
+
+use super::*;
+
+/// Returns a 4x3 map with 2x2 sized tiles at origin (0, 0).
+///   ....
+///   .#..
+///   ####
+fn get_map() -> TileCollisionMap<()> {
+    TileCollisionMap::new((), (0.0, 0.0), (2.0, 2.0), 0, 0)
+        .with_rows_from_top(vec![
+            vec![false, false, false, false],
+            vec![false, true, false, false],
+            vec![true, true, true, true],
+        ])
+}
+
+fn get_rect(top: f32, bottom: f32, left: f32, right: f32) -> Rect {
+    Rect::builder()
+        .top(top)
+        .bottom(bottom)
+        .left(left)
+        .right(right)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn rows_are_stored_from_bottom() {
+    let map = get_map();
+    assert!(map.is_solid(0, 0));
+    assert!(map.is_solid(1, 1));
+    assert!(!map.is_solid(0, 1));
+    assert!(!map.is_solid(1, 2));
+}
+
+#[test]
+fn cells_outside_of_map_are_not_solid() {
+    let map = get_map();
+    assert!(!map.is_solid(4, 0));
+    assert!(!map.is_solid(0, 3));
+}
+
+#[test]
+fn cell_rect_is_positioned_from_origin() {
+    let map = get_map();
+    let rect = map.cell_rect(1, 1);
+    assert_eq!(
+        (rect.top, rect.bottom, rect.left, rect.right),
+        (4.0, 2.0, 2.0, 4.0)
+    );
+}
+
+#[test]
+fn finds_intersecting_solid_rects() {
+    let map = get_map();
+    assert_eq!(map.solid_rects_in(&get_rect(3.0, 1.0, 1.0, 3.0)).len(), 3);
+    assert!(map.solid_rects_in(&get_rect(6.0, 4.0, 0.0, 8.0)).is_empty());
+    assert!(map.solid_rects_in(&get_rect(2.0, -2.0, -4.0, 0.0)).is_empty());
+}
+
+#[test]
+fn touching_rect_does_not_collide() {
+    let map = get_map();
+    let rect = CollisionRect::<(), ()>::builder()
+        .id(0)
+        .tag(())
+        .rect(get_rect(4.0, 2.0, 4.0, 6.0))
+        .build()
+        .unwrap();
+    assert!(!map.collides_with(&rect));
+}
+
+#[test]
+fn padded_collision_rect_includes_touching_cells() {
+    let map = get_map();
+    let rect = get_rect(4.0, 2.0, 4.0, 6.0);
+    assert!(map.gen_collision_rect(0, &rect, &None).is_none());
+    let collision_rect = map
+        .gen_collision_rect(0, &rect, &Some(Point::new(1.0, 1.0)))
+        .expect("Should find touching cells with padding");
+    assert_eq!(collision_rect.rects.len(), 4);
+}
+
+fn get_map_data(cells: Vec<bool>) -> TileCollisionMapData<()> {
+    TileCollisionMapData {
+        tag:       (),
+        origin:    (0.0, 0.0),
+        tile_size: (2.0, 2.0),
+        columns:   2,
+        rows:      2,
+        cells,
+    }
+}
+
+#[test]
+fn map_data_with_wrong_amount_of_cells_is_rejected() {
+    assert!(TileCollisionMap::try_from(get_map_data(vec![true; 3])).is_err());
+    assert!(TileCollisionMap::try_from(get_map_data(vec![true; 5])).is_err());
+    let map = TileCollisionMap::try_from(get_map_data(vec![true; 4]))
+        .expect("Map data with columns * rows cells should be valid");
+    assert!(map.is_solid(1, 1));
+}
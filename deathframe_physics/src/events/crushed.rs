@@ -0,0 +1,13 @@
+use core::amethyst::ecs::Entity;
+
+/// Published by the `MoveEntitiesSystem`, when the `SolidPushable`
+/// `entity` is squeezed between the `SolidPusher` entity `by`
+/// and an immovable solid (a `Solid` without `SolidPushable`,
+/// or a solid tile). If the pusher pushes it through other pushables,
+/// `by` is still the pusher, which started the push.
+/// Published every frame, for as long as the entity is being crushed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crushed {
+    pub entity: Entity,
+    pub by:     Entity,
+}
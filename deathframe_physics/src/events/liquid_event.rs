@@ -0,0 +1,13 @@
+use core::amethyst::ecs::Entity;
+
+/// Published by the `ApplyLiquidsSystem`,
+/// when an entity enters or exits a `LiquidVolume`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiquidEvent {
+    /// The `entity` has started to submerge into
+    /// the `liquid` entity's `LiquidVolume`.
+    Enter { entity: Entity, liquid: Entity },
+    /// The `entity` is no longer submerged in
+    /// the `liquid` entity's `LiquidVolume`.
+    Exit { entity: Entity, liquid: Entity },
+}
@@ -0,0 +1,10 @@
+//! Events published by this crate's systems.
+//! Read them from their respective `EventChannel` resources.
+
+pub mod prelude {
+    pub use super::crushed::Crushed;
+    pub use super::liquid_event::LiquidEvent;
+}
+
+mod crushed;
+mod liquid_event;
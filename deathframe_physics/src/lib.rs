@@ -6,6 +6,7 @@ extern crate serde;
 
 pub mod collision;
 pub mod components;
+pub mod events;
 pub mod query;
 pub mod systems;
 
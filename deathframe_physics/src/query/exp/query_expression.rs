@@ -12,6 +12,9 @@ where
     IsState(QueryValueState),
     /// Checks the given tag for equality with this collider's tag.
     IsTag(C),
+    /// Checks if the collision is with the solid cells
+    /// of a `TileCollisionMap`.
+    IsTileMap,
     /// _All_ given expressions must be true.
     And(Vec<QueryExpression<C>>),
     /// _Any_ of the given expressions must be true.
@@ -20,6 +20,9 @@ mod query_prelude {
 
 mod query_helpers;
 
+#[cfg(test)]
+mod tests;
+
 use crate::collision::tag::CollisionTag;
 use crate::components::prelude::Collider;
 
@@ -34,5 +34,7 @@ where
         }
 
         QExp::IsTag(target_tag) => target_tag == &collision.tag,
+
+        QExp::IsTileMap => collision.is_tile_map,
     }
 }
@@ -0,0 +1,38 @@
+//! Test module `deathframe_physics::query`
+
+use super::prelude::*;
+use crate::collision::prelude::*;
+use crate::components::prelude::Collider;
+
+const TILE_MAP_ID: u32 = 0;
+const ENTITY_ID: u32 = 1;
+
+/// Returns a collider, which collides with a tile map below it,
+/// and with another entity to its left.
+fn get_collider() -> Collider<()> {
+    let mut collider = Collider::new(());
+    collider.set_collision_with(TILE_MAP_ID, CollisionSide::Bottom, (), true);
+    collider.set_collision_with(ENTITY_ID, CollisionSide::Left, (), false);
+    collider
+}
+
+#[test]
+fn find_query_finds_tile_map_collision() {
+    let collider = get_collider();
+    let exp = QueryExpression::IsTileMap;
+    let found = collider.query::<FindQuery<()>>().exp(&exp).run();
+    assert_eq!(found.map(|collision| collision.id), Some(TILE_MAP_ID));
+    assert!(found.map(CollisionData::is_tile_map).unwrap_or(false));
+}
+
+#[test]
+fn filter_query_filters_tile_map_collisions() {
+    let collider = get_collider();
+    let exp = QueryExpression::Not(Box::new(QueryExpression::IsTileMap));
+    let filtered = collider.query::<FilterQuery<()>>().exp(&exp).run();
+    let ids = filtered
+        .into_iter()
+        .map(|collision| collision.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![ENTITY_ID]);
+}
@@ -0,0 +1,141 @@
+use super::system_prelude::*;
+use std::collections::HashSet;
+
+/// Applies buoyancy and drag to entities with `Velocity`,
+/// which are submerged in a `LiquidVolume`.
+/// How deep an entity is submerged is calculated from its `Hitbox`
+/// (or `Size`, if it has no `Hitbox`) and the liquid's surface line.
+/// The entity's `Gravity` is counteracted proportionally to its depth
+/// and the liquid's `density`, and the liquid's `drag` is applied
+/// to its velocity.
+/// Publishes `LiquidEvent`s, when entities enter or exit a liquid.
+#[derive(Default)]
+pub struct ApplyLiquidsSystem {
+    submerged: HashSet<(Entity, Entity)>,
+}
+
+impl<'a> System<'a> for ApplyLiquidsSystem {
+    type SystemData = (
+        Read<'a, Time>,
+        Entities<'a>,
+        Write<'a, EventChannel<LiquidEvent>>,
+        ReadStorage<'a, LiquidVolume>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, Hitbox>,
+        ReadStorage<'a, Size>,
+        ReadStorage<'a, Gravity>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Unloaded>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            time,
+            entities,
+            mut liquid_event_channel,
+            liquid_store,
+            transform_store,
+            hitbox_store,
+            size_store,
+            gravity_store,
+            mut velocity_store,
+            unloaded_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds() as f32;
+
+        let liquids = (
+            &entities,
+            &liquid_store,
+            &transform_store,
+            !&unloaded_store,
+        )
+            .join()
+            .map(|(entity, liquid, transform, _)| {
+                let trans = transform.translation();
+                (entity, liquid, Point::new(trans.x, trans.y))
+            })
+            .collect::<Vec<_>>();
+
+        let mut submerged = HashSet::new();
+
+        for (
+            entity,
+            transform,
+            velocity,
+            hitbox_opt,
+            size_opt,
+            gravity_opt,
+            _,
+        ) in (
+            &entities,
+            &transform_store,
+            &mut velocity_store,
+            hitbox_store.maybe(),
+            size_store.maybe(),
+            gravity_store.maybe(),
+            !&unloaded_store,
+        )
+            .join()
+        {
+            let rect = {
+                let trans = transform.translation();
+                gen_bounding_rect(
+                    &Point::new(trans.x, trans.y),
+                    hitbox_opt,
+                    size_opt,
+                )
+            };
+
+            for (liquid_entity, liquid, liquid_pos) in liquids.iter() {
+                if liquid_entity == &entity {
+                    continue;
+                }
+
+                let depth = liquid.submersion(liquid_pos, &rect);
+                if depth <= 0.0 {
+                    continue;
+                }
+
+                submerged.insert((entity, *liquid_entity));
+
+                Axis::for_each(|axis| {
+                    if let Some(gravity) = gravity_opt {
+                        if gravity.enabled.by_axis(&axis) {
+                            if let Some(grav) = gravity.get(&axis) {
+                                velocity.increase(
+                                    &axis,
+                                    -grav * liquid.density * depth * dt,
+                                );
+                            }
+                        }
+                    }
+
+                    if liquid.drag > 0.0 {
+                        let vel = velocity.get(&axis);
+                        velocity.set(
+                            &axis,
+                            vel * (-liquid.drag * depth * dt).exp(),
+                        );
+                    }
+                });
+            }
+        }
+
+        for (entity, liquid) in submerged.difference(&self.submerged) {
+            liquid_event_channel.single_write(LiquidEvent::Enter {
+                entity: *entity,
+                liquid: *liquid,
+            });
+        }
+        for (entity, liquid) in self.submerged.difference(&submerged) {
+            liquid_event_channel.single_write(LiquidEvent::Exit {
+                entity: *entity,
+                liquid: *liquid,
+            });
+        }
+
+        self.submerged = submerged;
+    }
+}
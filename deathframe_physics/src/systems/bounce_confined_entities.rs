@@ -0,0 +1,62 @@
+use super::system_prelude::*;
+
+/// Reflects the `Velocity` of `Confined` entities with
+/// `ConfineMode::Bounce`, when they hit an edge of their confined rect.
+/// Reads the `ConfinementHit` events published by the core crate's
+/// `ConfineEntitiesSystem`, so it should run after that system.
+/// If it runs before it, hits are reflected one frame later.
+/// Its event reader is registered in `setup`,
+/// so no hits are missed before its first run.
+#[derive(Default)]
+pub struct BounceConfinedEntitiesSystem {
+    reader_id: Option<ReaderId<ConfinementHit>>,
+}
+
+impl<'a> System<'a> for BounceConfinedEntitiesSystem {
+    type SystemData = (
+        Write<'a, EventChannel<ConfinementHit>>,
+        ReadStorage<'a, Confined>,
+        WriteStorage<'a, Velocity>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.reader_id = Some(
+            world
+                .fetch_mut::<EventChannel<ConfinementHit>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            mut confinement_hit_channel,
+            confined_store,
+            mut velocity_store,
+        ): Self::SystemData,
+    ) {
+        let reader_id = self
+            .reader_id
+            .get_or_insert_with(|| confinement_hit_channel.register_reader());
+
+        for hit in confinement_hit_channel.read(reader_id) {
+            let axis = hit.side.axis();
+            let is_bouncing = confined_store
+                .get(hit.entity)
+                .map(|confined| confined.mode(&axis) == &ConfineMode::Bounce)
+                .unwrap_or(false);
+            if !is_bouncing {
+                continue;
+            }
+            if let Some(velocity) = velocity_store.get_mut(hit.entity) {
+                // Point the velocity away from the hit side,
+                // so multiple hits don't flip it back again.
+                let speed = velocity.get(&axis).abs();
+                let reflected =
+                    if hit.side.is_negative() { speed } else { -speed };
+                velocity.set(&axis, reflected);
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use crate::query::prelude::*;
+use std::marker::PhantomData;
+
+/// Activates `Checkpoint`s, when a `Respawnable` entity, which uses
+/// checkpoints, starts colliding with a checkpoint entity.
+/// The activated checkpoint is stored in the `Checkpoints` resource,
+/// and a `CheckpointActivated` event is published.
+/// Already active checkpoints are not activated again.
+pub struct HandleCheckpointsSystem<C>
+where
+    C: CollisionTag,
+{
+    _c: PhantomData<C>,
+}
+
+impl<'a, C> System<'a> for HandleCheckpointsSystem<C>
+where
+    C: 'static + CollisionTag,
+{
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Checkpoint>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, Collider<C>>,
+        ReadStorage<'a, Respawnable>,
+        ReadStorage<'a, Unloaded>,
+        Write<'a, Checkpoints>,
+        Write<'a, EventChannel<CheckpointActivated>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            checkpoint_store,
+            transform_store,
+            collider_store,
+            respawnable_store,
+            unloaded_store,
+            mut checkpoints,
+            mut checkpoint_activated_channel,
+        ): Self::SystemData,
+    ) {
+        let checkpoint_ids: Vec<Index> =
+            (&entities, &checkpoint_store, &transform_store, !&unloaded_store)
+                .join()
+                .map(|(entity, _, _, _)| entity.id())
+                .collect();
+
+        if checkpoint_ids.is_empty() {
+            return;
+        }
+
+        let query_exp = {
+            use crate::query::exp::prelude_variants::*;
+            IsState(Enter)
+        };
+
+        for (activator, collider, respawnable, _) in (
+            &entities,
+            &collider_store,
+            &respawnable_store,
+            !&unloaded_store,
+        )
+            .join()
+        {
+            if !respawnable.uses_checkpoints() {
+                continue;
+            }
+
+            let collisions = collider
+                .query::<FilterQuery<C>>()
+                .filter_ids(&checkpoint_ids)
+                .exp(&query_exp)
+                .run();
+
+            for collision in collisions {
+                let checkpoint_entity = entities.entity(collision.id);
+                if let (Some(checkpoint), Some(transform)) = (
+                    checkpoint_store.get(checkpoint_entity),
+                    transform_store.get(checkpoint_entity),
+                ) {
+                    if checkpoints.is_active(checkpoint.id()) {
+                        continue;
+                    }
+                    let trans = transform.translation();
+                    let data = CheckpointData {
+                        id:          checkpoint.id().to_string(),
+                        spawn_point: checkpoint.spawn_point((trans.x, trans.y)),
+                    };
+                    checkpoints.activate(data.clone());
+                    checkpoint_activated_channel.single_write(
+                        CheckpointActivated {
+                            checkpoint: checkpoint_entity,
+                            activator,
+                            data,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<C> Default for HandleCheckpointsSystem<C>
+where
+    C: CollisionTag,
+{
+    fn default() -> Self {
+        Self {
+            _c: Default::default(),
+        }
+    }
+}
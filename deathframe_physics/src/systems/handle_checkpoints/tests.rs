@@ -0,0 +1,114 @@
+//! Test activating checkpoints with the `HandleCheckpointsSystem`
+
+use super::*;
+use core::amethyst::ecs::{Builder, RunNow, WorldExt};
+
+fn setup() -> (
+    World,
+    HandleCheckpointsSystem<()>,
+    ReaderId<CheckpointActivated>,
+) {
+    let mut world = World::new();
+    let mut system = HandleCheckpointsSystem::<()>::default();
+    System::setup(&mut system, &mut world);
+    let reader_id = world
+        .write_resource::<EventChannel<CheckpointActivated>>()
+        .register_reader();
+    (world, system, reader_id)
+}
+
+/// Creates a checkpoint entity at the given position,
+/// with a spawn offset of one unit up.
+fn create_checkpoint(world: &mut World, id: &str, x: f32) -> Entity {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(x, 0.0, 0.0);
+    world
+        .create_entity()
+        .with(transform)
+        .with(Checkpoint::new(id).with_spawn_offset((0.0, 1.0)))
+        .build()
+}
+
+/// Creates an activator entity, which starts colliding
+/// with the given checkpoint entity.
+fn create_activator(
+    world: &mut World,
+    checkpoint: Entity,
+    uses_checkpoints: bool,
+) -> Entity {
+    let mut collider = Collider::new(());
+    collider.set_collision_with(
+        checkpoint.id(),
+        CollisionSide::Left,
+        (),
+        false,
+    );
+    world
+        .create_entity()
+        .with(collider)
+        .with(Respawnable::new().with_checkpoints(uses_checkpoints))
+        .build()
+}
+
+fn read_activated(
+    world: &World,
+    reader_id: &mut ReaderId<CheckpointActivated>,
+) -> Vec<CheckpointActivated> {
+    world
+        .read_resource::<EventChannel<CheckpointActivated>>()
+        .read(reader_id)
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn activates_checkpoint_on_enter() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let checkpoint = create_checkpoint(&mut world, "first", 5.0);
+    let activator = create_activator(&mut world, checkpoint, true);
+
+    system.run_now(&world);
+    let data = CheckpointData {
+        id:          String::from("first"),
+        spawn_point: (5.0, 1.0),
+    };
+    assert_eq!(world.read_resource::<Checkpoints>().active(), Some(&data));
+    assert_eq!(read_activated(&world, &mut reader_id), vec![
+        CheckpointActivated {
+            checkpoint,
+            activator,
+            data,
+        }
+    ]);
+
+    system.run_now(&world);
+    assert!(
+        read_activated(&world, &mut reader_id).is_empty(),
+        "Doesn't activate an already active checkpoint again"
+    );
+}
+
+#[test]
+fn ignores_activators_not_using_checkpoints() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let checkpoint = create_checkpoint(&mut world, "first", 5.0);
+    let _ = create_activator(&mut world, checkpoint, false);
+
+    system.run_now(&world);
+    assert_eq!(world.read_resource::<Checkpoints>().active(), None);
+    assert!(read_activated(&world, &mut reader_id).is_empty());
+}
+
+#[test]
+fn ignores_unloaded_checkpoints() {
+    let (mut world, mut system, _) = setup();
+    let checkpoint = create_checkpoint(&mut world, "first", 5.0);
+    let _ = create_activator(&mut world, checkpoint, true);
+    world
+        .write_storage::<Unloaded>()
+        .insert(checkpoint, Unloaded::default())
+        .unwrap();
+
+    system.run_now(&world);
+    assert_eq!(world.read_resource::<Checkpoints>().active(), None);
+}
@@ -0,0 +1,108 @@
+use super::system_prelude::*;
+use crate::components::ClimbUpdate;
+
+/// Handles `Climber` entities' climbing on `Climbable` areas.
+/// A climber starts climbing, when it has a non-zero climb intent,
+/// while it overlaps with a climbable area, and keeps climbing
+/// until it leaves the area (or `Climber::stop_climbing` is called).
+/// While climbing, its gravity is suspended, it moves vertically with
+/// its climbing speed, and it is snapped to the climbable's horizontal center.
+/// Climbers can stand on top of _one-way-top_ climbables,
+/// and start climbing down from there.
+/// Should run after `ApplyGravitySystem` and before `MoveEntitiesSystem`.
+#[derive(Default)]
+pub struct HandleClimbingSystem;
+
+impl<'a> System<'a> for HandleClimbingSystem {
+    type SystemData = (
+        Read<'a, Time>,
+        Entities<'a>,
+        ReadStorage<'a, Climbable>,
+        WriteStorage<'a, Climber>,
+        WriteStorage<'a, Transform>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Hitbox>,
+        ReadStorage<'a, Size>,
+        ReadStorage<'a, Unloaded>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            time,
+            entities,
+            climbable_store,
+            mut climber_store,
+            mut transform_store,
+            mut velocity_store,
+            hitbox_store,
+            size_store,
+            unloaded_store,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds() as f32;
+
+        let climbables = (
+            &entities,
+            &climbable_store,
+            &transform_store,
+            !&unloaded_store,
+        )
+            .join()
+            .map(|(entity, climbable, transform, _)| {
+                let trans = transform.translation();
+                let area = climbable
+                    .rect
+                    .clone()
+                    .with_offset(&Point::new(trans.x, trans.y));
+                (entity, climbable.one_way_top, area)
+            })
+            .collect::<Vec<_>>();
+
+        for (
+            entity,
+            climber,
+            transform,
+            velocity,
+            hitbox_opt,
+            size_opt,
+            _,
+        ) in (
+            &entities,
+            &mut climber_store,
+            &mut transform_store,
+            &mut velocity_store,
+            hitbox_store.maybe(),
+            size_store.maybe(),
+            !&unloaded_store,
+        )
+            .join()
+        {
+            let rect = {
+                let trans = transform.translation();
+                gen_bounding_rect(
+                    &Point::new(trans.x, trans.y),
+                    hitbox_opt,
+                    size_opt,
+                )
+            };
+
+            let areas = climbables
+                .iter()
+                .filter(|(climbable_entity, _, _)| climbable_entity != &entity)
+                .map(|(_, one_way_top, area)| (area, *one_way_top));
+
+            match climber.update(dt, &rect, velocity.y, areas) {
+                ClimbUpdate::Climb { x, y } => {
+                    transform.set_translation_x(x);
+                    velocity.clear(&Axis::X);
+                    velocity.set(&Axis::Y, y);
+                }
+                ClimbUpdate::Land { y } => {
+                    velocity.set(&Axis::Y, y);
+                }
+                ClimbUpdate::None => (),
+            }
+        }
+    }
+}
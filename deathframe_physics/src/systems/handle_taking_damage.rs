@@ -6,11 +6,26 @@ use std::marker::PhantomData;
 /// This system makes `TakesDamage` entities take damage
 /// from `DealsDamage` entities, that it collides with.
 /// Dealing damage means _losing health_.
+/// `DealsDamage` with `DamageMode::OnEnter` deals its damage once,
+/// when the collision starts; with `DamageMode::PerSecond` it deals
+/// its damage continuously, while the collision persists.
+/// When the collision starts, the `DealsDamage`'s knockback
+/// is applied to the damaged entity's `Velocity`.
+/// Damage from collisions with tags ignored by the entity's
+/// `DamageTagFilter` is ignored.
+/// Entities with an active `Invulnerable` component don't take damage.
+/// If `TakesDamage` has an `invulnerability`, it is added to the entity
+/// after it takes a hit, with the hit as its exempt action.
+/// So the `UpdateHealthSystem` applies the hit either way,
+/// whether it runs before or after this system.
 pub struct HandleTakingDamageSystem<C>
 where
     C: CollisionTag,
 {
-    _c: PhantomData<C>,
+    /// Fractional damage for `PerSecond` damage, which hasn't been dealt yet,
+    /// for pairs of damaged entity IDs and damage dealing entity IDs.
+    damage_remainders: HashMap<(Index, Index), f32>,
+    _c:                PhantomData<C>,
 }
 
 impl<'a, C> System<'a> for HandleTakingDamageSystem<C>
@@ -19,42 +34,73 @@ where
 {
     type SystemData = (
         Entities<'a>,
+        Read<'a, Time>,
         ReadStorage<'a, TakesDamage>,
+        ReadStorage<'a, DamageTagFilter<C>>,
         ReadStorage<'a, DealsDamage>,
         ReadStorage<'a, Collider<C>>,
         WriteStorage<'a, HealthActionQueue>,
+        WriteStorage<'a, Velocity>,
+        WriteStorage<'a, Invulnerable>,
     );
 
     fn run(
         &mut self,
         (
             entities,
+            time,
             takes_damage_store,
+            damage_tag_filter_store,
             deals_damage_store,
             collider_store,
             mut health_action_queue_store,
+            mut velocity_store,
+            mut invulnerable_store,
         ): Self::SystemData,
     ) {
+        let dt = time.delta_seconds();
+
         let mut damage_map = HashMap::new();
 
         for (entity, deals_damage) in (&entities, &deals_damage_store).join() {
-            damage_map.insert(entity.id(), deals_damage.damage);
+            damage_map.insert(entity.id(), deals_damage);
         }
 
         let damage_dealing_ids: Vec<Index> =
             damage_map.keys().cloned().collect();
 
-        for (_entity, _takes_damage, collider, health_action_queue) in (
+        let mut damage_remainders = HashMap::new();
+        let mut new_invulnerables = Vec::new();
+
+        for (
+            entity,
+            takes_damage,
+            damage_tag_filter_opt,
+            collider,
+            health_action_queue,
+            mut velocity_opt,
+            invulnerable_opt,
+        ) in (
             &entities,
             &takes_damage_store,
+            damage_tag_filter_store.maybe(),
             &collider_store,
             &mut health_action_queue_store,
+            (&mut velocity_store).maybe(),
+            invulnerable_store.maybe(),
         )
             .join()
         {
+            if invulnerable_opt
+                .map(Invulnerable::is_active)
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
             let query_exp = {
                 use crate::query::exp::prelude_variants::*;
-                IsState(Enter)
+                Not(Box::new(IsState(Leave)))
             };
 
             let collisions = collider
@@ -63,22 +109,135 @@ where
                 .exp(&query_exp)
                 .run();
 
+
+            let mut became_invulnerable = false;
+
             for collision in collisions {
-                if let Some(damage) = damage_map.get(&collision.id) {
-                    health_action_queue.lose(*damage);
+                if damage_tag_filter_opt
+                    .map(|filter| filter.ignores_tag(&collision.tag))
+                    .unwrap_or(false)
+                {
+                    continue;
+                }
+                if let Some(deals_damage) = damage_map.get(&collision.id) {
+                    let is_enter = match &collision.state {
+                        CollisionState::Enter(_) => true,
+                        _ => false,
+                    };
+                    let damage = deals_damage.damage as f32
+                        * takes_damage.damage_factor(&deals_damage.damage_type);
+
+                    match deals_damage.mode {
+                        DamageMode::OnEnter => {
+                            let damage = damage.round() as HitPoints;
+                            if is_enter && damage > 0 {
+                                health_action_queue.lose(damage);
+                                if let Some(invulnerability) =
+                                    takes_damage.invulnerability.as_ref()
+                                {
+                                    let invulnerable = invulnerability
+                                        .clone()
+                                        .with_exempt_action(HealthAction::Lose(
+                                            damage,
+                                        ));
+                                    new_invulnerables
+                                        .push((entity, invulnerable));
+                                    became_invulnerable = true;
+                                }
+                            }
+                        }
+                        DamageMode::PerSecond => {
+                            let key = (entity.id(), collision.id);
+                            let total = self
+                                .damage_remainders
+                                .get(&key)
+                                .cloned()
+                                .unwrap_or(0.0)
+                                + damage * dt;
+                            let whole = total.floor();
+                            if whole >= 1.0 {
+                                health_action_queue.lose(whole as HitPoints);
+                            }
+                            damage_remainders.insert(key, total - whole);
+                        }
+                    }
+
+                    if is_enter {
+                        if let (Some(knockback), Some(velocity), Some(side)) = (
+                            deals_damage.knockback,
+                            velocity_opt.as_mut(),
+                            collision.side(),
+                        ) {
+                            apply_knockback(velocity, knockback, side);
+                        }
+                    }
+                }
+
+                // Don't take any more hits this frame,
+                // after becoming invulnerable.
+                if became_invulnerable {
+                    break;
                 }
             }
         }
+
+        for (entity, invulnerable) in new_invulnerables {
+            invulnerable_store
+                .insert(entity, invulnerable)
+                .expect("Couldn't insert Invulnerable for damaged entity");
+        }
+
+        self.damage_remainders = damage_remainders;
     }
 }
 
+/// Sets the velocity to the given knockback,
+/// pointed away from the given collision side.
+fn apply_knockback(
+    velocity: &mut Velocity,
+    knockback: (f32, f32),
+    side: &CollisionSide,
+) {
+    // The direction away from the collision side on each axis,
+    // or `None` if the side doesn't specify a direction on that axis.
+    let direction: (Option<f32>, Option<f32>) = match side {
+        CollisionSide::Left => (Some(1.0), None),
+        CollisionSide::Right => (Some(-1.0), None),
+        CollisionSide::Top => (None, Some(-1.0)),
+        CollisionSide::Bottom => (None, Some(1.0)),
+        CollisionSide::Inner { x, y } => (
+            x.as_ref().map(|x| match x {
+                CollisionInnerSideX::Left => 1.0,
+                CollisionInnerSideX::Right => -1.0,
+            }),
+            y.as_ref().map(|y| match y {
+                CollisionInnerSideY::Top => -1.0,
+                CollisionInnerSideY::Bottom => 1.0,
+            }),
+        ),
+    };
+
+    Axis::for_each(|axis| {
+        let knockback = knockback.by_axis(&axis);
+        if knockback != 0.0 {
+            let vel = if let Some(direction) = direction.by_axis(&axis) {
+                knockback.abs() * direction
+            } else {
+                knockback
+            };
+            velocity.set(&axis, vel);
+        }
+    });
+}
+
 impl<C> Default for HandleTakingDamageSystem<C>
 where
     C: CollisionTag,
 {
     fn default() -> Self {
         Self {
-            _c: Default::default(),
+            damage_remainders: Default::default(),
+            _c:                Default::default(),
         }
     }
 }
@@ -1,9 +1,14 @@
 pub mod prelude {
     pub use super::apply_base_friction::ApplyBaseFrictionSystem;
     pub use super::apply_gravity::ApplyGravitySystem;
+    pub use super::apply_liquids::ApplyLiquidsSystem;
+    pub use super::bounce_confined_entities::BounceConfinedEntitiesSystem;
+    pub use super::handle_checkpoints::HandleCheckpointsSystem;
+    pub use super::handle_climbing::HandleClimbingSystem;
     pub use super::handle_taking_damage::HandleTakingDamageSystem;
     pub use super::move_entities::MoveEntitiesSystem;
     pub use super::update_collisions::UpdateCollisionsSystem;
+    pub use super::update_hitbox_mirror::UpdateHitboxMirrorSystem;
 }
 
 mod system_prelude {
@@ -12,15 +17,21 @@ mod system_prelude {
     pub(crate) use crate::collision::tag::CollisionTag;
     pub(crate) use crate::components::helpers::WithCollisionTag;
     pub(crate) use crate::components::prelude::*;
+    pub(crate) use crate::events::prelude::*;
     pub(crate) use core::geo::prelude::*;
     pub(crate) use core::systems::system_prelude::*;
 }
 
 mod apply_base_friction;
 mod apply_gravity;
+mod apply_liquids;
+mod bounce_confined_entities;
+mod handle_checkpoints;
+mod handle_climbing;
 mod handle_taking_damage;
 mod move_entities;
 mod update_collisions;
+mod update_hitbox_mirror;
 
 pub(crate) mod helpers {
     use super::system_prelude::*;
@@ -28,10 +39,10 @@ pub(crate) mod helpers {
     use core::amethyst::ecs::Component;
     use std::ops::Deref;
 
-    pub fn gen_collision_grid<C, W, DT>(
+    pub fn gen_collision_grid<C, W, DT, DH>(
         entities: &Entities,
         transforms: &Storage<Transform, DT>,
-        hitboxes: &ReadStorage<Hitbox>,
+        hitboxes: &Storage<Hitbox, DH>,
         with_collision_tag_comps: &ReadStorage<W>,
         unloaded_store: &ReadStorage<Unloaded>,
         padding_opt: Option<Point>,
@@ -40,6 +51,7 @@ pub(crate) mod helpers {
         C: CollisionTag,
         W: Component + WithCollisionTag<C>,
         DT: Deref<Target = MaskedStorage<Transform>>,
+        DH: Deref<Target = MaskedStorage<Hitbox>>,
     {
         let mut grid = CollisionGrid::<Entity, C, ()>::default();
 
@@ -101,4 +113,31 @@ pub(crate) mod helpers {
 
         collision_rect
     }
+
+    /// Returns the absolute `Rect`, which encloses all of the entity's
+    /// `Hitbox` rects. Falls back to the entity's `Size`, if it has no `Hitbox`,
+    /// or to just its position, if it has neither.
+    pub fn gen_bounding_rect(
+        entity_pos: &Point,
+        hitbox_opt: Option<&Hitbox>,
+        size_opt: Option<&Size>,
+    ) -> Rect {
+        let rect = hitbox_opt
+            .and_then(|hitbox| {
+                hitbox.rects.iter().cloned().fold(None, |acc, rect| {
+                    Some(match acc {
+                        None => rect,
+                        Some(acc) => Rect {
+                            top:    acc.top.max(rect.top),
+                            bottom: acc.bottom.min(rect.bottom),
+                            left:   acc.left.min(rect.left),
+                            right:  acc.right.max(rect.right),
+                        },
+                    })
+                })
+            })
+            .or_else(|| size_opt.map(Rect::from))
+            .unwrap_or_default();
+        rect.with_offset(entity_pos)
+    }
 }
@@ -0,0 +1,650 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+/// This system is responsible for moving all entities with `Transform` and `Velocity`,
+/// by manipulating their `Transform` appropriately.
+/// It also handles collision with `Solid` entities; Solid entities may not move into each other.
+/// Solid cells of `TileCollisionMap`s are treated like immovable `Solid` entities.
+/// `SolidPusher` entities push `SolidPushable` entities, when moving into them.
+/// If a pushable entity is squeezed between a pusher and an immovable solid
+/// (a `Solid` without `SolidPushable`, or a solid tile), a `Crushed` event
+/// is published every frame, and the pusher's crush damage (if any)
+/// is queued in the crushed entity's `HealthActionQueue`,
+/// once when the crushing starts. When pushing through other pushables,
+/// the pusher which started the push is the one crushing.
+/// A pusher can only push pushables, if their total weight
+/// is within the pusher's strength.
+pub struct MoveEntitiesSystem<C>
+where
+    C: CollisionTag,
+{
+    /// Pairs of crushed entities and the pushers crushing them,
+    /// from the previous frame.
+    crushing: HashSet<(Entity, Entity)>,
+    _c:       PhantomData<C>,
+}
+
+impl<'a, C> System<'a> for MoveEntitiesSystem<C>
+where
+    C: 'static + CollisionTag,
+{
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Time>,
+        WriteStorage<'a, Transform>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Hitbox>,
+        ReadStorage<'a, Solid<C>>,
+        ReadStorage<'a, TileCollisionMap<C>>,
+        WriteStorage<'a, SolidPusher>,
+        ReadStorage<'a, SolidPushable>,
+        WriteStorage<'a, NonPreciseMovement>,
+        ReadStorage<'a, Unloaded>,
+        WriteStorage<'a, HealthActionQueue>,
+        Write<'a, EventChannel<Crushed>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut transform_store,
+            mut velocity_store,
+            hitbox_store,
+            solid_store,
+            tile_map_store,
+            mut solid_pusher_store,
+            solid_pushable_store,
+            mut non_precise_movement_store,
+            unloaded_store,
+            mut health_action_queue_store,
+            mut crushed_channel,
+        ): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+
+        Self::run_without_collision(
+            dt,
+            &entities,
+            &mut transform_store,
+            &mut velocity_store,
+            &solid_store,
+            &unloaded_store,
+        );
+
+        let crushed = Self::run_with_collision(
+            dt,
+            &entities,
+            &mut transform_store,
+            &mut velocity_store,
+            &solid_store,
+            &tile_map_store,
+            &mut solid_pusher_store,
+            &solid_pushable_store,
+            &hitbox_store,
+            &mut non_precise_movement_store,
+            &unloaded_store,
+        );
+
+        for &(entity, by) in crushed.iter() {
+            let is_crush_start = !self.crushing.contains(&(entity, by));
+            if let Some(crush_damage) = solid_pusher_store
+                .get(by)
+                .and_then(|pusher| pusher.crush_damage)
+                .filter(|_| is_crush_start)
+            {
+                if let Some(health_action_queue) =
+                    health_action_queue_store.get_mut(entity)
+                {
+                    health_action_queue.lose(crush_damage);
+                }
+            }
+            crushed_channel.single_write(Crushed { entity, by });
+        }
+
+        self.crushing = crushed;
+    }
+}
+
+impl<C> MoveEntitiesSystem<C>
+where
+    C: 'static + CollisionTag,
+{
+    fn run_without_collision(
+        dt: f32,
+        entities: &Entities,
+        transform_store: &mut WriteStorage<Transform>,
+        velocity_store: &mut WriteStorage<Velocity>,
+        solid_store: &ReadStorage<Solid<C>>,
+        unloaded_store: &ReadStorage<Unloaded>,
+    ) {
+        for (_, transform, velocity, _, _) in (
+            entities,
+            transform_store,
+            velocity_store,
+            !solid_store,
+            !unloaded_store,
+        )
+            .join()
+        {
+            transform.prepend_translation_x(velocity.x * dt);
+            transform.prepend_translation_y(velocity.y * dt);
+        }
+    }
+
+    fn run_with_collision(
+        dt: f32,
+        entities: &Entities,
+        transform_store: &mut WriteStorage<Transform>,
+        velocity_store: &mut WriteStorage<Velocity>,
+        solid_store: &ReadStorage<Solid<C>>,
+        tile_map_store: &ReadStorage<TileCollisionMap<C>>,
+        solid_pusher_store: &mut WriteStorage<SolidPusher>,
+        solid_pushable_store: &ReadStorage<SolidPushable>,
+        hitbox_store: &ReadStorage<Hitbox>,
+        non_precise_movement_store: &mut WriteStorage<NonPreciseMovement>,
+        unloaded_store: &ReadStorage<Unloaded>,
+    ) -> HashSet<(Entity, Entity)> {
+        // Generate the collision grid.
+        let mut collision_grid = gen_collision_grid(
+            entities,
+            &*transform_store,
+            hitbox_store,
+            solid_store,
+            unloaded_store,
+            None,
+        );
+
+        // Tile maps are tested against directly,
+        // instead of adding each solid cell to the collision grid.
+        let tile_maps = (tile_map_store, !unloaded_store)
+            .join()
+            .map(|(tile_map, _)| tile_map)
+            .collect::<Vec<_>>();
+
+        // Create entity data hashmap for transforms and velocities.
+        // At the end of the function each entity's transform and velocity
+        // components are updated with theses values.
+        // Note, that this data is generated for all movable entities,
+        // even unloaded entities.
+        let mut entity_data_map = EntityDataMap::new();
+        for (entity, transform, _solid, _hitbox) in
+            (entities, &*transform_store, solid_store, hitbox_store).join()
+        {
+            entity_data_map.insert(entity, EntityData::from(transform));
+        }
+
+        // Pairs of crushed entities and the pushers crushing them.
+        let mut crushed = HashSet::new();
+
+        for (
+            entity,
+            _transform,
+            velocity,
+            _solid,
+            _hitbox,
+            non_precise_movement_opt,
+            _,
+        ) in (
+            entities,
+            &*transform_store,
+            velocity_store,
+            solid_store,
+            hitbox_store,
+            non_precise_movement_store.maybe(),
+            !unloaded_store,
+        )
+            .join()
+        {
+            let pushed_weights = move_entity(
+                dt,
+                &mut collision_grid,
+                &tile_maps,
+                &mut entity_data_map,
+                entity,
+                velocity,
+                entities,
+                solid_store,
+                hitbox_store,
+                &*solid_pusher_store,
+                solid_pushable_store,
+                non_precise_movement_opt,
+                &mut crushed,
+            );
+
+            if let Some(pusher) = solid_pusher_store.get_mut(entity) {
+                Axis::for_each(|axis| {
+                    if let Some(weight) = pushed_weights.by_axis(&axis) {
+                        pusher.set_pushed_weight(&axis, weight);
+                    }
+                });
+            }
+        }
+
+        // Apply changed entity data to respective components.
+        for (entity, EntityData { position }) in entity_data_map {
+            if let Some(transform) = transform_store.get_mut(entity) {
+                transform.set_translation_x(position.x);
+                transform.set_translation_y(position.y);
+            }
+        }
+
+        crushed
+    }
+}
+
+fn move_entity<C>(
+    dt: f32,
+    collision_grid: &mut CollisionGrid<Entity, C, ()>,
+    tile_maps: &[&TileCollisionMap<C>],
+    entity_data_map: &mut EntityDataMap,
+    entity: Entity,
+    velocity: &mut Velocity,
+    entities: &Entities,
+    solid_store: &ReadStorage<Solid<C>>,
+    hitbox_store: &ReadStorage<Hitbox>,
+    pusher_store: &WriteStorage<SolidPusher>,
+    pushable_store: &ReadStorage<SolidPushable>,
+    mut non_precise_movement_opt: Option<&mut NonPreciseMovement>,
+    crushed: &mut HashSet<(Entity, Entity)>,
+) -> PushedWeights
+where
+    C: CollisionTag,
+{
+    let pusher_opt = pusher_store.get(entity);
+    let strength = pusher_opt.and_then(|pusher| pusher.strength);
+    let mut pushed_weights: PushedWeights = (None, None);
+
+    Axis::for_each(|axis| {
+        let speed_factor = pusher_opt
+            .map(|pusher| pusher.speed_factor(&axis))
+            .unwrap_or(1.0);
+        let vel = match axis {
+            Axis::X => velocity.x * dt * speed_factor,
+            Axis::Y => velocity.y * dt * speed_factor,
+        };
+        let sign = if vel != 0.0 { vel.signum() } else { 0.0 };
+        let (abs, rem) = if let Some(non_precise) =
+            non_precise_movement_opt.as_mut()
+        {
+            // Only move by whole pixels,
+            // carry the float remainder over to the next frame.
+            let whole = non_precise.accumulate(&axis, vel);
+            (whole.abs() as usize, 0.0)
+        } else {
+            (vel.abs() as usize, vel % 1.0)
+        };
+        let mut pushed_weight: Option<f32> = None;
+
+        // Move one pixel at a time
+        'pixel_loop: for _ in 0 .. abs {
+            let mut push = PushState::new(entity, strength);
+            let result = move_entity_by_one(
+                collision_grid,
+                tile_maps,
+                entity_data_map,
+                entity,
+                &axis,
+                sign,
+                entities,
+                solid_store,
+                hitbox_store,
+                pusher_store,
+                pushable_store,
+                &mut push,
+                crushed,
+            );
+            pushed_weight = Some(pushed_weight.unwrap_or(0.0).max(push.weight));
+            if !result.did_move() {
+                // Entity did not move, would have been in collision.
+                // kill the relevant velocity and break out of the loop.
+                velocity.clear(&axis);
+                if let Some(non_precise) = non_precise_movement_opt.as_mut() {
+                    non_precise.clear_remainder(&axis);
+                }
+                break 'pixel_loop;
+            }
+        }
+
+        // Try to move by the floating point remainder.
+        // Only if entity does NOT have `NonPreciseMovement` component.
+        if rem != 0.0 {
+            let mut push = PushState::new(entity, strength);
+            let result = move_entity_by_one(
+                collision_grid,
+                tile_maps,
+                entity_data_map,
+                entity,
+                &axis,
+                rem,
+                entities,
+                solid_store,
+                hitbox_store,
+                pusher_store,
+                pushable_store,
+                &mut push,
+                crushed,
+            );
+            pushed_weight = Some(pushed_weight.unwrap_or(0.0).max(push.weight));
+            if !result.did_move() {
+                // Entity did not move, would have been in collision.
+                // kill the relevant velocity.
+                velocity.clear(&axis);
+            }
+        }
+
+        // Move to the nearest whole pixel, if the entity is moving
+        // in the direction of it, and if its position would round to it.
+        // Only if entity has `NonPreciseMovement` with `snap_to_nearest`.
+        let should_snap = non_precise_movement_opt
+            .as_ref()
+            .map(|non_precise| non_precise.snap_to_nearest)
+            .unwrap_or(false);
+        if should_snap {
+            let step_opt = entity_data_map.get(&entity).and_then(
+                |EntityData { position }| {
+                    let pos = (position.x, position.y).by_axis(&axis);
+                    let pos_rem = pos - pos.floor();
+                    if sign < 0.0 && pos_rem > 0.0 && pos_rem < 0.5 {
+                        Some(-pos_rem)
+                    } else if sign > 0.0 && pos_rem >= 0.5 {
+                        Some(1.0 - pos_rem)
+                    } else {
+                        None
+                    }
+                },
+            );
+            if let Some(step) = step_opt {
+                if !move_entity_by_one(
+                    collision_grid,
+                    tile_maps,
+                    entity_data_map,
+                    entity,
+                    &axis,
+                    step,
+                    entities,
+                    solid_store,
+                    hitbox_store,
+                    pusher_store,
+                    pushable_store,
+                    &mut PushState::new(entity, strength),
+                    crushed,
+                )
+                .did_move()
+                {
+                    velocity.clear(&axis);
+                }
+            }
+        }
+
+        *(&mut pushed_weights).by_axis(&axis) = pushed_weight;
+    });
+
+    pushed_weights
+}
+
+/// The result of trying to move an entity by a single step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MoveResult {
+    /// The entity moved.
+    Moved,
+    /// The entity was blocked by an immovable solid;
+    /// a `Solid` without `SolidPushable`, or a solid tile.
+    Blocked,
+    /// The entity was blocked by a `SolidPushable`, which couldn't be
+    /// moved, or which this entity can't push.
+    BlockedByPushable,
+    /// The entity couldn't push the pushables in its way, because their
+    /// total weight would exceed the pushing `SolidPusher`'s strength.
+    TooHeavy,
+}
+
+impl MoveResult {
+    fn did_move(&self) -> bool {
+        self == &MoveResult::Moved
+    }
+}
+
+fn move_entity_by_one<C>(
+    collision_grid: &mut CollisionGrid<Entity, C, ()>,
+    tile_maps: &[&TileCollisionMap<C>],
+    entity_data_map: &mut EntityDataMap,
+    entity: Entity,
+    axis: &Axis,
+    step: f32,
+    entities: &Entities,
+    solid_store: &ReadStorage<Solid<C>>,
+    hitbox_store: &ReadStorage<Hitbox>,
+    pusher_store: &WriteStorage<SolidPusher>,
+    pushable_store: &ReadStorage<SolidPushable>,
+    push: &mut PushState,
+    crushed: &mut HashSet<(Entity, Entity)>,
+) -> MoveResult
+where
+    C: CollisionTag,
+{
+    // TODO
+    // Remove entity data entry here, is re-inserted at the end of the function
+    // let EntityData { mut position } = entity_data_map.remove(&entity).expect(
+    //     "Should have `EntityData` for entity in `move_entity_by_one` function",
+    // );
+    let EntityData { mut position } =
+        if let Some(data) = entity_data_map.remove(&entity) {
+            data
+        } else {
+            return MoveResult::Blocked;
+        };
+
+    let solid = solid_store
+        .get(entity)
+        .expect("Entity should have `Solid` in `move_entity_by_one` function");
+    let hitbox = hitbox_store
+        .get(entity)
+        .expect("Entity should have `Hitbox` in `move_entity_by_one` function");
+    let pusher_opt = pusher_store.get(entity);
+
+    let new_position = {
+        match axis {
+            Axis::X => Point::new(position.x + step, position.y),
+            Axis::Y => Point::new(position.x, position.y + step),
+        }
+    };
+
+    let is_pusher = pusher_opt.is_some();
+
+    let collision_rect = CollisionRect::<C, ()>::builder()
+        .id(entity.id())
+        .tag(solid.tag.clone())
+        .rects(
+            hitbox
+                .rects
+                .clone()
+                .into_iter()
+                .map(|rect| rect.with_offset(&new_position))
+                .collect(),
+        )
+        .build()
+        .unwrap();
+
+    let is_position_in_tile_collision = tile_maps
+        .iter()
+        .any(|tile_map| tile_map.collides_with(&collision_rect));
+
+    let colliding_rects: Vec<_> = collision_grid
+        .colliding_with(&collision_rect)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let mut set_new_position =
+        |collision_grid: &mut CollisionGrid<Entity, C, ()>| {
+            // Update position
+            position.x = new_position.x;
+            position.y = new_position.y;
+            // Update position in collision_grid
+            if let Some(collision_rect) = collision_grid.get_mut(&entity) {
+                let new_rect = gen_collision_rect(
+                    &entity,
+                    &position,
+                    hitbox,
+                    solid.tag.clone(),
+                    &None,
+                );
+                *collision_rect = new_rect;
+            }
+        };
+
+    let result = if is_position_in_tile_collision {
+        // New position would be in collision with a solid tile,
+        // which can never be pushed.
+        MoveResult::Blocked
+    } else if colliding_rects.is_empty() {
+        // New position is NOT in collision, apply position
+        set_new_position(collision_grid);
+        MoveResult::Moved
+    } else if is_pusher {
+        // SolidPusher is in collision, so try to push colliding entities,
+        // and move self if they were moved successfully.
+        let mut result = MoveResult::Moved;
+        for colliding in colliding_rects {
+            let colliding_entity = entities.entity(colliding.id);
+            let colliding_result = if let Some(pushable) =
+                pushable_store.get(colliding_entity)
+            {
+                if push.pushed_entities.contains(&colliding_entity) {
+                    MoveResult::Moved
+                } else if !push.try_add_weight(pushable.weight) {
+                    // Pushed entities are too heavy
+                    // for the pusher's strength.
+                    MoveResult::TooHeavy
+                } else {
+                    push.pushed_entities.insert(colliding_entity);
+                    let push_result = move_entity_by_one(
+                        collision_grid,
+                        tile_maps,
+                        entity_data_map,
+                        colliding_entity,
+                        axis,
+                        step,
+                        entities,
+                        solid_store,
+                        hitbox_store,
+                        pusher_store,
+                        pushable_store,
+                        push,
+                        crushed,
+                    );
+                    match push_result {
+                        MoveResult::Moved | MoveResult::TooHeavy => push_result,
+                        MoveResult::Blocked => {
+                            // Pushable entity is squeezed between
+                            // the pusher and an immovable solid.
+                            crushed.insert((colliding_entity, push.pusher));
+                            MoveResult::BlockedByPushable
+                        }
+                        MoveResult::BlockedByPushable => {
+                            MoveResult::BlockedByPushable
+                        }
+                    }
+                }
+            } else {
+                MoveResult::Blocked
+            };
+            if !colliding_result.did_move() {
+                result = colliding_result;
+                break;
+            }
+        }
+        if result.did_move() {
+            // Move this entity, because all colliding entities were moved
+            set_new_position(collision_grid);
+        }
+        result
+    } else if colliding_rects.iter().all(|colliding| {
+        pushable_store.contains(entities.entity(colliding.id))
+    }) {
+        // New position would be in collision with pushables,
+        // which this entity can't push.
+        MoveResult::BlockedByPushable
+    } else {
+        // New position would be in collision with an immovable solid.
+        MoveResult::Blocked
+    };
+
+    // Re-insert position and velocity entity data
+    entity_data_map.insert(entity, EntityData { position });
+
+    result
+}
+
+impl<C> Default for MoveEntitiesSystem<C>
+where
+    C: 'static + CollisionTag,
+{
+    fn default() -> Self {
+        Self {
+            crushing: Default::default(),
+            _c:       Default::default(),
+        }
+    }
+}
+
+type EntityDataMap = HashMap<Entity, EntityData>;
+
+/// The total weight pushed on each axis, if the entity moved on that axis.
+type PushedWeights = (Option<f32>, Option<f32>);
+
+/// Data for a single push step of a `SolidPusher`,
+/// shared between all entities that are pushed in that step.
+struct PushState {
+    /// The `SolidPusher` entity, which started the push.
+    /// Pushables crushed anywhere in the push chain are crushed by it.
+    pusher:          Entity,
+    /// Entities which were already pushed in this step.
+    pushed_entities: HashSet<Entity>,
+    /// The strength of the `SolidPusher`, which started the push.
+    strength:        Option<f32>,
+    /// Total weight of all entities pushed in this step.
+    weight:          f32,
+}
+
+impl PushState {
+    fn new(pusher: Entity, strength: Option<f32>) -> Self {
+        Self {
+            pusher,
+            pushed_entities: HashSet::new(),
+            strength,
+            weight: 0.0,
+        }
+    }
+
+    /// Adds the given weight to the total pushed weight.
+    /// Returns `false` and doesn't add the weight, if the total
+    /// weight would exceed the pusher's strength.
+    fn try_add_weight(&mut self, weight: f32) -> bool {
+        let total = self.weight + weight;
+        if self.strength.map(|strength| total > strength).unwrap_or(false) {
+            false
+        } else {
+            self.weight = total;
+            true
+        }
+    }
+}
+
+struct EntityData {
+    pub position: Point,
+}
+
+impl<'a> From<&'a Transform> for EntityData {
+    fn from(transform: &'a Transform) -> Self {
+        let trans = transform.translation();
+        let position = Point::new(trans.x, trans.y);
+        Self { position }
+    }
+}
@@ -0,0 +1,220 @@
+//! Test pushing and crushing of the `MoveEntitiesSystem`
+
+use super::*;
+use core::amethyst::ecs::{Builder, RunNow, WorldExt};
+
+const CRUSH_DAMAGE: HitPoints = 2;
+
+fn setup() -> (World, MoveEntitiesSystem<()>, ReaderId<Crushed>) {
+    let mut world = World::new();
+    let mut system = MoveEntitiesSystem::<()>::default();
+    System::setup(&mut system, &mut world);
+    world.write_resource::<Time>().set_delta_seconds(1.0);
+    let reader_id = world
+        .write_resource::<EventChannel<Crushed>>()
+        .register_reader();
+    (world, system, reader_id)
+}
+
+/// Creates a 2x2 solid entity centered at the given x position.
+fn create_solid(world: &mut World, x: f32) -> Entity {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(x, 0.0, 0.0);
+    let rect = Rect::builder()
+        .top(1.0)
+        .bottom(-1.0)
+        .left(-1.0)
+        .right(1.0)
+        .build()
+        .unwrap();
+    world
+        .create_entity()
+        .with(transform)
+        .with(Solid::new(()))
+        .with(Hitbox::new().with_rect(rect))
+        .build()
+}
+
+fn create_pushable(world: &mut World, x: f32, weight: f32) -> Entity {
+    let entity = create_solid(world, x);
+    world
+        .write_storage::<SolidPushable>()
+        .insert(entity, SolidPushable::new().with_weight(weight))
+        .unwrap();
+    world
+        .write_storage::<HealthActionQueue>()
+        .insert(entity, HealthActionQueue::default())
+        .unwrap();
+    entity
+}
+
+fn create_pusher(world: &mut World, x: f32, pusher: SolidPusher) -> Entity {
+    let entity = create_solid(world, x);
+    world
+        .write_storage::<SolidPusher>()
+        .insert(entity, pusher)
+        .unwrap();
+    entity
+}
+
+/// Runs the system for one frame, with the pusher moving right
+/// by one pixel, and returns the published `Crushed` events.
+fn run_frame(
+    world: &World,
+    system: &mut MoveEntitiesSystem<()>,
+    reader_id: &mut ReaderId<Crushed>,
+    pusher: Entity,
+) -> Vec<Crushed> {
+    world
+        .write_storage::<Velocity>()
+        .insert(pusher, Velocity::new(1.0, 0.0))
+        .unwrap();
+    system.run_now(world);
+    world
+        .read_resource::<EventChannel<Crushed>>()
+        .read(reader_id)
+        .cloned()
+        .collect()
+}
+
+fn crush_damage_taken(world: &World, entity: Entity) -> usize {
+    world
+        .write_storage::<HealthActionQueue>()
+        .get_mut(entity)
+        .unwrap()
+        .drain_actions()
+        .filter(|action| action == &HealthAction::Lose(CRUSH_DAMAGE))
+        .count()
+}
+
+#[test]
+fn crushes_pushable_against_immovable_solid() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let pusher = create_pusher(
+        &mut world,
+        0.0,
+        SolidPusher::new().with_crush_damage(CRUSH_DAMAGE),
+    );
+    let pushable = create_pushable(&mut world, 2.0, 1.0);
+    let _wall = create_solid(&mut world, 4.0);
+
+    let crushed = run_frame(&world, &mut system, &mut reader_id, pusher);
+    assert_eq!(crushed, vec![Crushed {
+        entity: pushable,
+        by:     pusher,
+    }]);
+    assert_eq!(crush_damage_taken(&world, pushable), 1);
+}
+
+#[test]
+fn deals_crush_damage_once_per_crush() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let pusher = create_pusher(
+        &mut world,
+        0.0,
+        SolidPusher::new().with_crush_damage(CRUSH_DAMAGE),
+    );
+    let pushable = create_pushable(&mut world, 2.0, 1.0);
+    let _wall = create_solid(&mut world, 4.0);
+
+    for _ in 0 .. 3 {
+        let crushed = run_frame(&world, &mut system, &mut reader_id, pusher);
+        assert_eq!(crushed.len(), 1, "Crushed every frame");
+    }
+    assert_eq!(
+        crush_damage_taken(&world, pushable),
+        1,
+        "Crush damage is only dealt when the crush starts"
+    );
+}
+
+#[test]
+fn doesnt_crush_pushable_blocked_by_pushable() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let pusher = create_pusher(&mut world, 0.0, SolidPusher::new());
+    let _pushable = create_pushable(&mut world, 2.0, 1.0);
+    let _other_pushable = create_pushable(&mut world, 4.0, 1.0);
+
+    let crushed = run_frame(&world, &mut system, &mut reader_id, pusher);
+    assert!(crushed.is_empty());
+}
+
+#[test]
+fn doesnt_crush_too_heavy_pushables() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let pusher = create_pusher(
+        &mut world,
+        0.0,
+        SolidPusher::new()
+            .with_strength(1.5)
+            .with_crush_damage(CRUSH_DAMAGE),
+    );
+    // A pushable, which pushes another pushable,
+    // so the total weight exceeds the pusher's strength.
+    let pushing_pushable = create_pushable(&mut world, 2.0, 1.0);
+    world
+        .write_storage::<SolidPusher>()
+        .insert(pushing_pushable, SolidPusher::new())
+        .unwrap();
+    let _pushable = create_pushable(&mut world, 4.0, 1.0);
+
+    let crushed = run_frame(&world, &mut system, &mut reader_id, pusher);
+    assert!(crushed.is_empty());
+    assert_eq!(crush_damage_taken(&world, pushing_pushable), 0);
+}
+
+#[test]
+fn crushes_by_pusher_pushing_through_pushables() {
+    let (mut world, mut system, mut reader_id) = setup();
+    let pusher = create_pusher(
+        &mut world,
+        0.0,
+        SolidPusher::new().with_crush_damage(CRUSH_DAMAGE),
+    );
+    let pushing_pushable = create_pushable(&mut world, 2.0, 1.0);
+    world
+        .write_storage::<SolidPusher>()
+        .insert(pushing_pushable, SolidPusher::new())
+        .unwrap();
+    let pushable = create_pushable(&mut world, 4.0, 1.0);
+    let _wall = create_solid(&mut world, 6.0);
+
+    let crushed = run_frame(&world, &mut system, &mut reader_id, pusher);
+    assert_eq!(crushed, vec![Crushed {
+        entity: pushable,
+        by:     pusher,
+    }]);
+    assert_eq!(crush_damage_taken(&world, pushable), 1);
+    assert_eq!(crush_damage_taken(&world, pushing_pushable), 0);
+}
+
+/// Creates an entity in a new `World`, for tests which need
+/// any entity, but no components.
+fn create_entity() -> Entity {
+    World::new().create_entity().build()
+}
+
+#[test]
+fn push_state_adds_any_weight_without_strength() {
+    let mut push = PushState::new(create_entity(), None);
+    assert!(push.try_add_weight(100.0));
+    assert!(push.try_add_weight(100.0));
+    assert_eq!(push.weight, 200.0);
+}
+
+#[test]
+fn push_state_adds_weight_up_to_strength() {
+    let mut push = PushState::new(create_entity(), Some(2.0));
+    assert!(push.try_add_weight(1.0));
+    assert!(push.try_add_weight(1.0), "Total equal to strength is allowed");
+    assert_eq!(push.weight, 2.0);
+}
+
+#[test]
+fn push_state_rejects_weight_above_strength() {
+    let mut push = PushState::new(create_entity(), Some(2.0));
+    assert!(push.try_add_weight(1.5));
+    assert!(!push.try_add_weight(1.0), "Total would exceed strength");
+    assert_eq!(push.weight, 1.5, "Rejected weight shouldn't be added");
+    assert!(push.try_add_weight(0.5), "Lighter weight still fits");
+}
@@ -1,14 +1,22 @@
+#[cfg(test)]
+mod tests;
+
 use super::system_prelude::*;
 use std::marker::PhantomData;
 
-const PADDING: (f32, f32) = (2.0, 2.0);
+const DEFAULT_PADDING: (f32, f32) = (2.0, 2.0);
 
 /// The `UpdateCollisionsSystem` is in charge of setting collision states for colliding entities.
 /// Entities with `CheckCollision` (and with `Collision`) check for collision against
 /// other entities with `Collision`.
+/// Solid cells of `TileCollisionMap`s are checked like `Collidable` entities;
+/// collisions with them are registered as collisions with the map's entity.
 /// Only checks for entities with either NO `Loadable` and NO `Loaded` components
 /// or for entities with `Loadable` AND `Loaded` components;
 /// does not check for entities with `Loadable` but NOT `Loaded` components.
+/// The padding is the tolerance used to detect touching entities, and to decide
+/// on which side a collision happened. Each `Collider` can have its own padding,
+/// otherwise this system's default padding is used (see `with_padding`).
 
 // NOTE:
 // Consider giving `UpdateCollisionsSystem` a `CollisionGrid` field, which stores the generated
@@ -17,9 +25,26 @@ const PADDING: (f32, f32) = (2.0, 2.0);
 // This might improve performance, as the `CollisionGrid` wouldn't be re-generated every frame.
 // It would have to re-generate and remove all `CollisionRect`s with moving entities each frame
 // though, so benchmarking would be needed to verify that this would be beneficial.
-pub struct UpdateCollisionsSystem<C>(PhantomData<C>)
+pub struct UpdateCollisionsSystem<C>
 where
-    C: CollisionTag;
+    C: CollisionTag,
+{
+    /// Default padding for `Collider`s without a custom padding.
+    padding: (f32, f32),
+    _c:      PhantomData<C>,
+}
+
+impl<C> UpdateCollisionsSystem<C>
+where
+    C: CollisionTag,
+{
+    /// Set the default padding, used for `Collider`s
+    /// without a custom padding. Defaults to `(2.0, 2.0)`.
+    pub fn with_padding(mut self, padding: (f32, f32)) -> Self {
+        self.padding = padding;
+        self
+    }
+}
 
 impl<'a, C> System<'a> for UpdateCollisionsSystem<C>
 where
@@ -31,6 +56,7 @@ where
         ReadStorage<'a, Hitbox>,
         WriteStorage<'a, Collider<C>>,
         ReadStorage<'a, Collidable<C>>,
+        ReadStorage<'a, TileCollisionMap<C>>,
         ReadStorage<'a, Unloaded>,
     );
 
@@ -42,19 +68,28 @@ where
             hitboxes,
             mut colliders,
             collidables,
+            tile_map_store,
             unloaded_store,
         ): Self::SystemData,
     ) {
         // Generate the collision grid.
+        // Its rects are not padded, instead each collider's rect is padded
+        // with the collider's own padding when checking for collision.
         let collision_grid = gen_collision_grid(
             &entities,
             &transforms,
             &hitboxes,
             &collidables,
             &unloaded_store,
-            Some(Point::new(PADDING.0, PADDING.1)),
+            None,
         );
 
+        // Collect all loaded tile maps, with their entity IDs.
+        let tile_maps = (&entities, &tile_map_store, !&unloaded_store)
+            .join()
+            .map(|(entity, tile_map, _)| (entity.id(), tile_map))
+            .collect::<Vec<_>>();
+
         // Loop through all Colliders, and check for collision in the CollisionGrid.
         for (entity, collider, hitbox, transform, _) in (
             &entities,
@@ -70,7 +105,9 @@ where
                 let trans = transform.translation();
                 Point::new(trans.x, trans.y)
             };
-            let mut collider_rect = CollisionRect::<C, ()>::builder()
+            let padding = collider.padding.unwrap_or(self.padding);
+            let padding_point = Point::new(padding.0, padding.1);
+            let mut query_rect = CollisionRect::<C, ()>::builder()
                 .id(entity_id)
                 .tag(collider.tag.clone())
                 .build()
@@ -78,15 +115,38 @@ where
 
             for hitbox_rect in hitbox.rects.iter() {
                 let rect = hitbox_rect.clone().with_offset(&entity_pos);
-                collider_rect.rects = vec![rect];
-                let colliding_rects =
-                    collision_grid.colliding_with(&collider_rect);
+                query_rect.rects =
+                    vec![rect.clone().with_padding(&padding_point)];
+                let tile_rects = tile_maps
+                    .iter()
+                    .filter_map(|(tile_map_id, tile_map)| {
+                        tile_map.gen_collision_rect(
+                            *tile_map_id,
+                            &query_rect.rects[0],
+                            &None,
+                        )
+                    })
+                    .filter(|tile_rect| {
+                        collision_check::do_rects_collide(
+                            &query_rect,
+                            tile_rect,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let colliding_rects = collision_grid
+                    .colliding_with(&query_rect)
+                    .into_iter()
+                    .map(|other_rect| (other_rect, false))
+                    .chain(tile_rects.iter().map(|tile_rect| (tile_rect, true)))
+                    .collect::<Vec<_>>();
                 if !colliding_rects.is_empty() {
-                    let rect_sides = RectSides::new(&collider_rect.rects[0]);
-                    for other_rect in colliding_rects {
+                    let rect_sides = RectSides::new(&rect, &padding);
+                    for (other_rect, is_tile_map) in colliding_rects {
                         // Check which side is in collision
-                        if let Some(side) =
-                            other_rect.rects.iter().find_map(|other_rect| {
+                        if let Some(side) = other_rect
+                            .rects
+                            .iter()
+                            .find_map(|other_rect| {
                                 rect_sides.collides_with(other_rect)
                             })
                         {
@@ -94,6 +154,7 @@ where
                                 other_rect.id,
                                 side,
                                 other_rect.tag.clone(),
+                                is_tile_map,
                             );
                         }
                     }
@@ -106,22 +167,32 @@ where
 }
 
 struct RectSides {
-    outer:  Rect,
-    inner:  Rect,
-    top:    Rect,
-    bottom: Rect,
-    left:   Rect,
-    right:  Rect,
+    padding: Point,
+    outer:   Rect,
+    inner:   Rect,
+    top:     Rect,
+    bottom:  Rect,
+    left:    Rect,
+    right:   Rect,
 }
 
 impl RectSides {
-    pub fn new(rect: &Rect) -> Self {
+    pub fn new(rect: &Rect, padding: &(f32, f32)) -> Self {
         let rect_center = rect.center();
+        // The padding is capped at a quarter of the rect's size, so the
+        // inner and side rects keep a valid size, even for rects
+        // that are smaller than twice the padding.
+        // Colliding rects are padded with the same capped padding,
+        // so touching rects never reach the inner rect.
+        let padding = (
+            padding.0.min((rect.right - rect.left) * 0.25),
+            padding.1.min((rect.top - rect.bottom) * 0.25),
+        );
         let base_rect = Rect::builder()
-            .top(rect.top - PADDING.1)
-            .bottom(rect.bottom + PADDING.1)
-            .left(rect.left + PADDING.0)
-            .right(rect.right - PADDING.0);
+            .top(rect.top - padding.1)
+            .bottom(rect.bottom + padding.1)
+            .left(rect.left + padding.0)
+            .right(rect.right - padding.0);
 
         let inner = base_rect.clone().build().unwrap();
         let top = base_rect
@@ -149,6 +220,7 @@ impl RectSides {
             .unwrap();
 
         Self {
+            padding: Point::new(padding.0, padding.1),
             outer: rect.clone(),
             inner,
             top,
@@ -158,9 +230,13 @@ impl RectSides {
         }
     }
 
+    /// Returns the side on which the given rect collides,
+    /// after padding it with this `RectSides`' padding.
     pub fn collides_with(&self, rect: &Rect) -> Option<CollisionSide> {
         use std::convert::TryFrom;
 
+        let rect = &rect.clone().with_padding(&self.padding);
+
         if !collision_check::do_rects_intersect(&self.outer, rect) {
             return None;
         }
@@ -202,6 +278,9 @@ where
     C: 'static + CollisionTag,
 {
     fn default() -> Self {
-        Self(Default::default())
+        Self {
+            padding: DEFAULT_PADDING,
+            _c:      Default::default(),
+        }
     }
 }
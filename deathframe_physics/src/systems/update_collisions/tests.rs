@@ -0,0 +1,48 @@
+//! Test the side detection of the `UpdateCollisionsSystem`
+
+use super::*;
+
+const PADDING: (f32, f32) = (2.0, 2.0);
+
+fn get_rect(top: f32, bottom: f32, left: f32, right: f32) -> Rect {
+    Rect::builder()
+        .top(top)
+        .bottom(bottom)
+        .left(left)
+        .right(right)
+        .build()
+        .unwrap()
+}
+
+/// A 4x4 bullet at the origin, which is smaller than twice the padding.
+fn get_bullet_sides() -> RectSides {
+    RectSides::new(&get_rect(2.0, -2.0, -2.0, 2.0), &PADDING)
+}
+
+#[test]
+fn small_rect_detects_touching_side() {
+    let sides = get_bullet_sides();
+    let wall_right = get_rect(10.0, -10.0, 2.0, 10.0);
+    let floor = get_rect(-2.0, -10.0, -10.0, 10.0);
+    assert_eq!(sides.collides_with(&wall_right), Some(CollisionSide::Right));
+    assert_eq!(sides.collides_with(&floor), Some(CollisionSide::Bottom));
+}
+
+#[test]
+fn small_rect_detects_inner_collision() {
+    let sides = get_bullet_sides();
+    let overlapping = get_rect(10.0, -10.0, 1.5, 10.0);
+    match sides.collides_with(&overlapping) {
+        Some(CollisionSide::Inner { x, .. }) => {
+            assert_eq!(x, Some(CollisionInnerSideX::Right))
+        }
+        side => panic!("Should collide inside, but got {:?}", side),
+    }
+}
+
+#[test]
+fn small_rect_ignores_distant_rect() {
+    let sides = get_bullet_sides();
+    let distant = get_rect(10.0, -10.0, 4.0, 10.0);
+    assert_eq!(sides.collides_with(&distant), None);
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests;
+
+use super::system_prelude::*;
+use std::marker::PhantomData;
+
+/// Mirrors the rects of `Hitbox`es, which have mirroring enabled.
+/// An entity's hitbox is mirrored on an axis, if its `Facing` component
+/// faces the negative direction (left or down) on that axis.
+/// Entities without `Facing` use the sign of their `Transform` scale instead.
+/// The hitbox of a `Solid` entity is only mirrored, if the mirrored rects
+/// wouldn't be in collision with other solids (or solid tiles),
+/// so turning around next to a wall doesn't push its hitbox into the wall.
+/// Until there is room, the hitbox stays as it is.
+pub struct UpdateHitboxMirrorSystem<C>(PhantomData<C>)
+where
+    C: CollisionTag;
+
+impl<'a, C> System<'a> for UpdateHitboxMirrorSystem<C>
+where
+    C: 'static + CollisionTag,
+{
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, Facing>,
+        WriteStorage<'a, Hitbox>,
+        ReadStorage<'a, Solid<C>>,
+        ReadStorage<'a, TileCollisionMap<C>>,
+        ReadStorage<'a, Unloaded>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            transform_store,
+            facing_store,
+            mut hitbox_store,
+            solid_store,
+            tile_map_store,
+            unloaded_store,
+        ): Self::SystemData,
+    ) {
+        let mut collision_grid = gen_collision_grid(
+            &entities,
+            &transform_store,
+            &hitbox_store,
+            &solid_store,
+            &unloaded_store,
+            None,
+        );
+        let tile_maps = (&tile_map_store, !&unloaded_store)
+            .join()
+            .map(|(tile_map, _)| tile_map)
+            .collect::<Vec<_>>();
+
+        for (entity, hitbox, transform_opt, facing_opt, solid_opt, _) in (
+            &entities,
+            &mut hitbox_store,
+            transform_store.maybe(),
+            facing_store.maybe(),
+            solid_store.maybe(),
+            !&unloaded_store,
+        )
+            .join()
+        {
+            Axis::for_each(|axis| {
+                if !hitbox.mirrors(&axis) {
+                    return;
+                }
+                let mirrored_opt = facing_opt
+                    .map(|facing| facing.is_flipped(&axis))
+                    .or_else(|| {
+                        transform_opt.map(|transform| {
+                            let scale = transform.scale();
+                            (scale.x, scale.y).by_axis(&axis) < 0.0
+                        })
+                    });
+                let mirrored = match mirrored_opt {
+                    Some(mirrored) if mirrored != hitbox.is_mirrored(&axis) => {
+                        mirrored
+                    }
+                    _ => return,
+                };
+
+                let mut mirrored_hitbox = hitbox.clone();
+                mirrored_hitbox.set_mirrored(&axis, mirrored);
+
+                if let (Some(solid), Some(transform)) =
+                    (solid_opt, transform_opt)
+                {
+                    let position = {
+                        let trans = transform.translation();
+                        Point::new(trans.x, trans.y)
+                    };
+                    let collision_rect = gen_collision_rect(
+                        &entity,
+                        &position,
+                        &mirrored_hitbox,
+                        solid.tag.clone(),
+                        &None,
+                    );
+                    let is_in_collision = collision_grid
+                        .collides_any(&collision_rect)
+                        || tile_maps.iter().any(|tile_map| {
+                            tile_map.collides_with(&collision_rect)
+                        });
+                    if is_in_collision {
+                        // Mirrored hitbox would be in collision,
+                        // try again next frame.
+                        return;
+                    }
+                    if let Some(grid_rect) = collision_grid.get_mut(&entity) {
+                        *grid_rect = collision_rect;
+                    }
+                }
+
+                *hitbox = mirrored_hitbox;
+            });
+        }
+    }
+}
+
+impl<C> Default for UpdateHitboxMirrorSystem<C>
+where
+    C: 'static + CollisionTag,
+{
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
@@ -0,0 +1,81 @@
+//! Test mirroring of solid hitboxes by the `UpdateHitboxMirrorSystem`
+
+use super::*;
+use core::amethyst::ecs::{Builder, RunNow, WorldExt};
+
+fn setup() -> (World, UpdateHitboxMirrorSystem<()>) {
+    let mut world = World::new();
+    let mut system = UpdateHitboxMirrorSystem::<()>::default();
+    System::setup(&mut system, &mut world);
+    (world, system)
+}
+
+/// Creates a solid entity at the given x position,
+/// with the given hitbox rect's left and right sides.
+fn create_solid(
+    world: &mut World,
+    x: f32,
+    (left, right): (f32, f32),
+    mirror_x: bool,
+) -> Entity {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(x, 0.0, 0.0);
+    let rect = Rect::builder()
+        .top(1.0)
+        .bottom(-1.0)
+        .left(left)
+        .right(right)
+        .build()
+        .unwrap();
+    world
+        .create_entity()
+        .with(transform)
+        .with(Solid::new(()))
+        .with(Hitbox::new().with_rect(rect).with_mirror_x(mirror_x))
+        .build()
+}
+
+/// Creates a solid entity, with a hitbox that reaches 4 units to its
+/// right, and which is mirrored when facing left (negative scale).
+fn create_turned_around(world: &mut World) -> Entity {
+    let entity = create_solid(world, 0.0, (0.0, 4.0), true);
+    world
+        .write_storage::<Transform>()
+        .get_mut(entity)
+        .unwrap()
+        .scale_mut()
+        .x = -1.0;
+    entity
+}
+
+fn is_mirrored(world: &World, entity: Entity) -> bool {
+    world
+        .read_storage::<Hitbox>()
+        .get(entity)
+        .unwrap()
+        .is_mirrored(&Axis::X)
+}
+
+#[test]
+fn mirrors_solid_hitbox_with_room() {
+    let (mut world, mut system) = setup();
+    let entity = create_turned_around(&mut world);
+    let _wall = create_solid(&mut world, -8.0, (-1.0, 1.0), false);
+
+    system.run_now(&world);
+    assert!(is_mirrored(&world, entity));
+}
+
+#[test]
+fn doesnt_mirror_solid_hitbox_into_wall() {
+    let (mut world, mut system) = setup();
+    let entity = create_turned_around(&mut world);
+    let wall = create_solid(&mut world, -3.0, (-1.0, 1.0), false);
+
+    system.run_now(&world);
+    assert!(!is_mirrored(&world, entity));
+
+    world.delete_entity(wall).unwrap();
+    system.run_now(&world);
+    assert!(is_mirrored(&world, entity), "Mirrors once there is room");
+}
@@ -9,6 +9,8 @@ use std::marker::PhantomData;
 /// The `AnimationBundle` registers the following systems:
 /// - `PlayAnimationsSystem` (named `"play_animations_system"`)
 /// - `SwitchAnimationsSystem` (named `"switch_animations_system"`)
+/// - `SwitchCheckpointAnimationsSystem`
+///   (named `"switch_checkpoint_animations_system"`)
 pub struct AnimationBundle<'a, AK>
 where
     AK: 'static + Hash + Eq + Send + Sync + Debug + Clone,
@@ -52,6 +54,17 @@ where
         _world: &mut World,
         builder: &mut DispatcherBuilder<'a, 'b>,
     ) -> Result<(), amethyst::Error> {
+        builder.add(
+            SwitchCheckpointAnimationsSystem::<AK>::default(),
+            &format!(
+                "switch_checkpoint_animations_system{}",
+                self.name_suffix
+                    .as_ref()
+                    .map(String::as_str)
+                    .unwrap_or_default()
+            ),
+            self.deps,
+        );
         builder.add(
             SwitchAnimationsSystem::<AK>::default(),
             &format!(
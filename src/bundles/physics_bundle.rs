@@ -6,11 +6,24 @@ use physics::systems::prelude::*;
 use std::marker::PhantomData;
 
 /// The `PhysicsBundle` registers the following systems:
+/// - `UpdateHitboxMirrorSystem` (named `"update_hitbox_mirror_system"`)
 /// - `MoveEntitiesSystem` (named `"move_entities_system"`)
 /// - `UpdateCollisionsSystem` (named `"update_collisions_system"`)
 /// - `ApplyBaseFrictionSystem` (named `"apply_base_friction_system"`)
 /// - `ApplyGravitySystem` (named `"apply_gravity_system"`)
+/// - `ApplyLiquidsSystem` (named `"apply_liquids_system"`)
+/// - `HandleClimbingSystem` (named `"handle_climbing_system"`)
 /// - `HandleTakingDamageSystem` (named `"handle_taking_damage_system"`)
+/// - `HandleCheckpointsSystem` (named `"handle_checkpoints_system"`)
+/// - `BounceConfinedEntitiesSystem`
+///   (named `"bounce_confined_entities_system"`)
+///
+/// The `BounceConfinedEntitiesSystem` reflects velocities on
+/// `ConfinementHit` events from the `ConfineEntitiesSystem`, which
+/// this bundle doesn't register. Add the `ConfineEntitiesSystem`
+/// to the dispatcher before this bundle, and pass its name to
+/// `with_deps`, so bounces are applied in the same frame.
+/// Otherwise they are applied one frame later.
 pub struct PhysicsBundle<'a, CU, CM>
 where
     CU: 'static + CollisionTag,
@@ -18,6 +31,7 @@ where
 {
     deps:                                &'a [&'a str],
     apply_base_friction_velocity_margin: Option<f32>,
+    update_collisions_padding:           Option<(f32, f32)>,
     _cm:                                 PhantomData<CM>,
     _cu:                                 PhantomData<CU>,
 }
@@ -47,6 +61,17 @@ where
         self.apply_base_friction_velocity_margin = Some(velocity_margin);
         self
     }
+
+    /// Set the `UpdateCollisionsSystem`'s default `padding`,
+    /// for `Collider`s without a custom padding.
+    /// See the `UpdateCollisionsSystem::with_padding` function.
+    pub fn with_update_collisions_padding(
+        mut self,
+        padding: (f32, f32),
+    ) -> Self {
+        self.update_collisions_padding = Some(padding);
+        self
+    }
 }
 
 impl<'a, 'b, 'c, CU, CM> SystemBundle<'a, 'b> for PhysicsBundle<'c, CU, CM>
@@ -78,17 +103,42 @@ where
             "apply_base_friction_system",
             &[self.deps, &["apply_gravity_system"]].concat(),
         );
+        builder.add(
+            ApplyLiquidsSystem::default(),
+            "apply_liquids_system",
+            &[self.deps, &["apply_base_friction_system"]].concat(),
+        );
+        builder.add(
+            HandleClimbingSystem::default(),
+            "handle_climbing_system",
+            &[self.deps, &["apply_liquids_system"]].concat(),
+        );
+        builder.add(
+            UpdateHitboxMirrorSystem::<CM>::default(),
+            "update_hitbox_mirror_system",
+            self.deps,
+        );
         builder.add(
             MoveEntitiesSystem::<CM>::default(),
             "move_entities_system",
             &[self.deps, &[
                 "apply_base_friction_system",
                 "apply_gravity_system",
+                "apply_liquids_system",
+                "handle_climbing_system",
+                "update_hitbox_mirror_system",
             ]]
             .concat(),
         );
         builder.add(
-            UpdateCollisionsSystem::<CU>::default(),
+            {
+                let system = UpdateCollisionsSystem::<CU>::default();
+                if let Some(padding) = self.update_collisions_padding {
+                    system.with_padding(padding)
+                } else {
+                    system
+                }
+            },
             "update_collisions_system",
             &[self.deps, &["move_entities_system"]].concat(),
         );
@@ -97,6 +147,16 @@ where
             "handle_taking_damage_system",
             &[self.deps, &["update_collisions_system"]].concat(),
         );
+        builder.add(
+            HandleCheckpointsSystem::<CU>::default(),
+            "handle_checkpoints_system",
+            &[self.deps, &["update_collisions_system"]].concat(),
+        );
+        builder.add(
+            BounceConfinedEntitiesSystem::default(),
+            "bounce_confined_entities_system",
+            self.deps,
+        );
         Ok(())
     }
 }
@@ -110,6 +170,7 @@ where
         Self {
             deps:                                Default::default(),
             apply_base_friction_velocity_margin: Default::default(),
+            update_collisions_padding:           Default::default(),
             _cm:                                 Default::default(),
             _cu:                                 Default::default(),
         }
@@ -0,0 +1,9 @@
+//! Re-exports events from enabled deathframe crates.
+
+pub mod prelude {
+    pub use core::events::prelude::*;
+    #[cfg(feature = "physics")]
+    pub use physics::events::prelude::*;
+}
+
+pub use prelude::*;
@@ -8,6 +8,7 @@ pub extern crate physics;
 
 pub mod bundles;
 pub mod components;
+pub mod events;
 pub mod resources;
 pub mod states;
 pub mod systems;